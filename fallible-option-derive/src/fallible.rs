@@ -0,0 +1,365 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::{ParseStream, Parser},
+    parse_quote, parse_quote_spanned,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit_mut::VisitMut,
+    Block, Expr, ExprCall, GenericArgument, Item, ItemFn, Macro, PathArguments, ReturnType, Stmt,
+    Token, Type,
+};
+
+/// Standard library macros whose arguments are all expressions, and within which
+/// `?` is rewritten.
+const EXPRESSION_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "eprint",
+    "eprintln",
+    "format",
+    "format_args",
+    "panic",
+    "print",
+    "println",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+/// Macros which never produce a value, and are therefore left untouched when they
+/// produce the return value of the function.
+const DIVERGING_MACROS: &[&str] = &[
+    "bail",
+    "fail",
+    "panic",
+    "todo",
+    "unimplemented",
+    "unreachable",
+];
+
+pub(crate) fn expand(args: TokenStream, mut function: ItemFn) -> syn::Result<TokenStream> {
+    if !args.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &args,
+            "`#[fallible]` does not take any arguments",
+        ));
+    }
+
+    let error = match &function.sig.output {
+        ReturnType::Type(_, ty) => unit_result_error(ty),
+        ReturnType::Default => None,
+    }
+    .ok_or_else(|| {
+        syn::Error::new_spanned(
+            &function.sig.output,
+            "`#[fallible]` can only be applied to functions returning `Result<(), E>`",
+        )
+    })?;
+
+    function.sig.output = parse_quote!(-> ::fallible_option::Fallible<#error>);
+
+    let mut returns = Returns { error: None };
+    returns.visit_block_mut(&mut function.block);
+    if let Some(error) = returns.error {
+        return Err(error);
+    }
+    rewrite_block(&mut function.block);
+
+    Ok(function.into_token_stream())
+}
+
+/// Returns `E`, if `ty` is a `Result<(), E>`.
+///
+/// Aliases such as `io::Result<()>` or `fmt::Result` do not name their error type,
+/// which is instead named through the `UnitResult` trait, which also verifies that
+/// the alias is in fact a `Result<(), E>`.
+fn unit_result_error(ty: &Type) -> Option<Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+
+    let generics = match &segment.arguments {
+        PathArguments::AngleBracketed(generics) => generics.args.iter().collect(),
+        PathArguments::None => Vec::new(),
+        PathArguments::Parenthesized(_) => return None,
+    };
+
+    match generics.as_slice() {
+        [GenericArgument::Type(Type::Tuple(unit)), GenericArgument::Type(error)]
+            if unit.elems.is_empty() =>
+        {
+            Some(error.clone())
+        }
+        [GenericArgument::Type(Type::Tuple(unit))] if unit.elems.is_empty() => {
+            Some(unit_result_alias_error(ty))
+        }
+        [] => Some(unit_result_alias_error(ty)),
+        _ => None,
+    }
+}
+
+/// Names the error type of `ty`, an alias of `Result<(), E>`.
+fn unit_result_alias_error(ty: &Type) -> Type {
+    parse_quote_spanned! { ty.span() =>
+        <#ty as ::fallible_option::__private::UnitResult>::Error
+    }
+}
+
+/// Rewrites the operand of every `return`, and every `?` expression within the function
+/// body, without descending into closures, async blocks or nested items.
+///
+/// `?` is expanded in place, since neither `Result` nor `Fallible` can be used with `?`
+/// in a function returning `Fallible` on stable.
+///
+/// `?` within the arguments of the macros listed in [`EXPRESSION_MACROS`] is rewritten
+/// as well, while `?` within any other macro is rejected, since its arguments cannot
+/// be rewritten reliably.
+struct Returns {
+    error: Option<syn::Error>,
+}
+
+impl Returns {
+    fn fail(&mut self, error: syn::Error) {
+        match &mut self.error {
+            Some(existing) => existing.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+}
+
+impl VisitMut for Returns {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) => {}
+            Expr::Return(ret) => {
+                if let Some(value) = &mut ret.expr {
+                    self.visit_expr_mut(value);
+                    rewrite_value(value);
+                }
+            }
+            Expr::Try(question) => {
+                self.visit_expr_mut(&mut question.expr);
+
+                let operand = &question.expr;
+                *expr = Expr::Verbatim(quote_spanned! { question.question_token.span =>
+                    match ::core::convert::Into::<::core::result::Result<_, _>>::into(#operand) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::fallible_option::FromFail::from_fail(err)
+                        }
+                    }
+                });
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        let Some(question) = find_question(mac.tokens.clone()) else {
+            return;
+        };
+
+        let name = macro_name(mac);
+        let mut args = match MacroArgs::parse(mac) {
+            Ok(args) => args,
+            Err(_) => return self.fail(unsupported_question(question, &name)),
+        };
+
+        if EXPRESSION_MACROS.contains(&name.as_str()) {
+            for expr in args.exprs_mut() {
+                self.visit_expr_mut(expr);
+            }
+            mac.tokens = args.into_token_stream();
+        } else {
+            let mut questions = Questions { first: None };
+            for expr in args.exprs_mut() {
+                questions.visit_expr_mut(expr);
+            }
+
+            if let Some(question) = questions.first {
+                self.fail(unsupported_question(question, &name));
+            }
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+/// Finds the first `?` expression outside of closures, async blocks and nested items.
+struct Questions {
+    first: Option<Span>,
+}
+
+impl VisitMut for Questions {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) => {}
+            Expr::Try(question) if self.first.is_none() => {
+                self.first = Some(question.question_token.span);
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+/// Arguments of a macro invocation, being either comma-separated expressions,
+/// or the `[value; count]` form of `vec!`.
+enum MacroArgs {
+    List(Punctuated<Expr, Token![,]>),
+    Repeat(Box<Expr>, Token![;], Box<Expr>),
+}
+
+impl MacroArgs {
+    fn parse(mac: &Macro) -> syn::Result<Self> {
+        Parser::parse2(
+            |input: ParseStream| {
+                let fork = input.fork();
+                if fork.parse::<Expr>().is_ok() && fork.peek(Token![;]) {
+                    return Ok(MacroArgs::Repeat(
+                        input.parse()?,
+                        input.parse()?,
+                        input.parse()?,
+                    ));
+                }
+
+                Punctuated::parse_terminated(input).map(MacroArgs::List)
+            },
+            mac.tokens.clone(),
+        )
+    }
+
+    fn exprs_mut(&mut self) -> Vec<&mut Expr> {
+        match self {
+            MacroArgs::List(exprs) => exprs.iter_mut().collect(),
+            MacroArgs::Repeat(value, _, count) => vec![&mut **value, &mut **count],
+        }
+    }
+}
+
+impl ToTokens for MacroArgs {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            MacroArgs::List(exprs) => exprs.to_tokens(tokens),
+            MacroArgs::Repeat(value, semi, count) => {
+                value.to_tokens(tokens);
+                semi.to_tokens(tokens);
+                count.to_tokens(tokens);
+            }
+        }
+    }
+}
+
+/// Returns the span of the first `?` token within `tokens`, if any.
+fn find_question(tokens: TokenStream) -> Option<Span> {
+    tokens.into_iter().find_map(|token| match token {
+        TokenTree::Punct(punct) if punct.as_char() == '?' => Some(punct.span()),
+        TokenTree::Group(group) => find_question(group.stream()),
+        _ => None,
+    })
+}
+
+fn macro_name(mac: &Macro) -> String {
+    mac.path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .unwrap_or_default()
+}
+
+fn unsupported_question(span: Span, name: &str) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "`?` cannot be rewritten within `{name}!` by `#[fallible]`, \
+             bind the value to a variable before the macro instead"
+        ),
+    )
+}
+
+/// Rewrites the tail expression of `block`, if it has one.
+fn rewrite_block(block: &mut Block) {
+    if let Some(Stmt::Expr(tail, None)) = block.stmts.last_mut() {
+        rewrite_value(tail);
+    }
+}
+
+/// Rewrites an expression producing the return value of the function.
+fn rewrite_value(expr: &mut Expr) {
+    match expr {
+        Expr::Block(block) => rewrite_block(&mut block.block),
+        Expr::Unsafe(block) => rewrite_block(&mut block.block),
+        Expr::Paren(paren) => rewrite_value(&mut paren.expr),
+        Expr::If(branch) => {
+            rewrite_block(&mut branch.then_branch);
+            if let Some((_, otherwise)) = &mut branch.else_branch {
+                rewrite_value(otherwise);
+            }
+        }
+        Expr::Match(matching) => {
+            for arm in &mut matching.arms {
+                rewrite_value(&mut arm.body);
+            }
+        }
+        Expr::Macro(mac) if DIVERGING_MACROS.contains(&macro_name(&mac.mac).as_str()) => {}
+        // These either diverge, or produce a value which cannot be inspected.
+        Expr::Return(_)
+        | Expr::Break(_)
+        | Expr::Continue(_)
+        | Expr::Loop(_)
+        | Expr::While(_)
+        | Expr::ForLoop(_) => {}
+        Expr::Call(call) if is_variant(call, "Ok") && is_unit(&call.args) => {
+            *expr = Expr::Verbatim(quote_spanned! { call.span() =>
+                ::fallible_option::Fallible::Success
+            });
+        }
+        Expr::Call(call) if is_variant(call, "Err") && call.args.len() == 1 => {
+            let error = &call.args[0];
+            *expr = Expr::Verbatim(quote_spanned! { call.span() =>
+                ::fallible_option::Fallible::Fail(#error)
+            });
+        }
+        other => {
+            *other = Expr::Verbatim(quote! {
+                ::fallible_option::Fallible::from(#other)
+            });
+        }
+    }
+}
+
+/// Returns true if `call` constructs the given `Result` variant, such as `Ok(..)`
+/// or `Result::Ok(..)`.
+fn is_variant(call: &ExprCall, variant: &str) -> bool {
+    match &*call.func {
+        Expr::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == variant),
+        _ => false,
+    }
+}
+
+/// Returns true if `args` consists of a single `()`.
+fn is_unit(args: &Punctuated<Expr, Token![,]>) -> bool {
+    match args.iter().collect::<Vec<_>>().as_slice() {
+        [Expr::Tuple(tuple)] => tuple.elems.is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,69 @@
+//! Procedural macros for [`fallible-option`](https://docs.rs/fallible-option).
+//!
+//! This crate is not intended to be used directly, the macros are re-exported by
+//! `fallible-option` when its `derive` feature is enabled.
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn};
+
+mod fallible;
+mod validate;
+
+/// Generates a `fn validate(&self) -> Fallible<ValidationError>` method, which checks
+/// each field against the validators listed in its `#[validate(...)]` attributes.
+///
+/// Fields are validated in declaration order, and validation stops at the first failure.
+///
+/// The following validators are supported:
+///
+/// * `range(<range>)` fails if the value of the field is not contained within `<range>`,
+///   which may be any range expression such as `10..=30` or `..100`.
+/// * `with = "<path>"` calls the function at `<path>` with a reference to the field.
+///   The function must return a `Fallible<&'static str>`, where the error is used as
+///   the reason of the resulting `ValidationError`.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    validate::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Rewrites a function returning `Result<(), E>` into one returning `Fallible<E>`.
+///
+/// Aliases of `Result<(), E>`, such as `io::Result<()>` or `fmt::Result`, are accepted
+/// as well.
+///
+/// The return type is replaced, and every expression which produces the return value
+/// of the function, being the tail expression and the operand of every `return`, is
+/// rewritten as follows:
+///
+/// * `Ok(())` becomes `Success`.
+/// * `Err(e)` becomes `Fail(e)`.
+/// * Any other expression `expr` becomes `Fallible::from(expr)`, which accepts both
+///   `Result<T, E>` and `Fallible<E>` values.
+///
+/// `if`, `match` and block expressions in these positions are rewritten branch by branch,
+/// and macros which never return, such as `panic!`, are left as they are.
+///
+/// The `?` operator may be applied to both `Result<T, E>` and `Fallible<E>` values, and
+/// converts the error into the error type of the function using `From`, as it would in a
+/// function returning `Result`. Within macros, `?` is only rewritten in the arguments of
+/// standard library macros such as `vec!`, `format!` or `assert!`, and is rejected within
+/// any other macro.
+///
+/// Closures, async blocks and nested items are left untouched, since they have return
+/// types of their own.
+#[proc_macro_attribute]
+pub fn fallible(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = proc_macro2::TokenStream::from(args);
+    let input = parse_macro_input!(input as ItemFn);
+
+    fallible::expand(args, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,145 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parenthesized, spanned::Spanned, Data, DeriveInput, Expr, ExprPath, ExprUnary, LitStr,
+    RangeLimits, UnOp,
+};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`Validate` can only be derived for structs",
+            ))
+        }
+    };
+
+    if let Some(attr) = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("validate"))
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "`#[validate(...)]` can only be applied to fields",
+        ));
+    }
+
+    let mut checks = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let (member, name) = match &field.ident {
+            Some(ident) => (ident.to_token_stream(), ident.to_string()),
+            None => {
+                let index = syn::Index::from(index);
+                (index.to_token_stream(), index.index.to_string())
+            }
+        };
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("validate"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("range") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    let range: Expr = content.parse()?;
+                    let reason = format!("must be within {}", display_range(&range));
+
+                    checks.push(quote_spanned! { range.span() =>
+                        if !::core::ops::RangeBounds::contains(&(#range), &self.#member) {
+                            return ::fallible_option::Fallible::Fail(
+                                ::fallible_option::ValidationError::new(#name, #reason),
+                            );
+                        }
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    let function: LitStr = meta.value()?.parse()?;
+                    let path: ExprPath = function.parse().map_err(|_| {
+                        syn::Error::new(
+                            function.span(),
+                            "expected the path of a validation function, like `with = \"check\"`",
+                        )
+                    })?;
+
+                    checks.push(quote_spanned! { path.span() =>
+                        if let ::fallible_option::Fallible::Fail(reason) = #path(&self.#member) {
+                            return ::fallible_option::Fallible::Fail(
+                                ::fallible_option::ValidationError::new(#name, reason),
+                            );
+                        }
+                    });
+                    Ok(())
+                } else {
+                    Err(meta
+                        .error("unsupported validator, expected `range(...)` or `with = \"...\"`"))
+                }
+            })?;
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Validates every field of this value, returning the first failure encountered.
+            pub fn validate(&self) -> ::fallible_option::Fallible<::fallible_option::ValidationError> {
+                #(#checks)*
+                ::fallible_option::Fallible::Success
+            }
+        }
+    })
+}
+
+/// Renders a range expression the way it would usually be written in source.
+///
+/// The token stream of an expression separates every token with a space, so the
+/// bounds of the range are rendered individually instead.
+fn display_range(range: &Expr) -> String {
+    match range {
+        Expr::Range(range) => {
+            let limits = match range.limits {
+                RangeLimits::HalfOpen(_) => "..",
+                RangeLimits::Closed(_) => "..=",
+            };
+
+            let mut rendered = String::new();
+            if let Some(start) = &range.start {
+                rendered.push_str(&display_expr(start));
+            }
+            rendered.push_str(limits);
+            if let Some(end) = &range.end {
+                rendered.push_str(&display_expr(end));
+            }
+            rendered
+        }
+        other => display_expr(other),
+    }
+}
+
+/// Renders a bound of a range, such as `-5`, `u16::MAX` or `(LIMIT)`.
+fn display_expr(expr: &Expr) -> String {
+    match expr {
+        Expr::Lit(lit) => lit.lit.to_token_stream().to_string(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => format!("-{}", display_expr(expr)),
+        Expr::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::"),
+        Expr::Paren(paren) => format!("({})", display_expr(&paren.expr)),
+        Expr::Group(group) => display_expr(&group.expr),
+        other => other.to_token_stream().to_string(),
+    }
+}
@@ -0,0 +1,154 @@
+//! Interoperability with [`anyhow`](https://docs.rs/anyhow).
+//!
+//! A `Fallible<anyhow::Error>` converts to and from an `anyhow::Result<()>` using `From`,
+//! and [`fallible_try!`](crate::fallible_try) propagates any `Fallible<E>` whose error is
+//! an [`std::error::Error`] out of a function returning `anyhow::Result<T>`. The original
+//! error is preserved, and can be recovered with [`anyhow::Error::downcast`].
+//!
+//! ```rust
+//! # use fallible_option::{fallible_try, Fallible::{self, Fail}};
+//! use std::io;
+//!
+//! fn rename() -> Fallible<io::Error> {
+//!     Fail(io::Error::from(io::ErrorKind::PermissionDenied))
+//! }
+//!
+//! fn save() -> anyhow::Result<u32> {
+//!     fallible_try!(rename());
+//!     Ok(10)
+//! }
+//!
+//! let err = save().unwrap_err().downcast::<io::Error>().unwrap();
+//! assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+//! ```
+//!
+//! Use [`Context`] in place of [`crate::Context`] to attach context as an `anyhow::Error`.
+
+use core::fmt::Display;
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Extension trait for annotating the error of a `Fallible` with context,
+/// producing a `Fallible<anyhow::Error>`.
+///
+/// This is the `anyhow` counterpart to [`crate::Context`], and should be
+/// imported instead of it where `anyhow` errors are preferred.
+///
+/// ```rust
+/// # use fallible_option::Fallible::{self, Fail, Success};
+/// use fallible_option::anyhow::Context;
+/// use std::io;
+///
+/// fn rename() -> Fallible<io::Error> {
+///     Fail(io::Error::from(io::ErrorKind::PermissionDenied))
+/// }
+///
+/// let err = rename().context("while renaming config").unwrap_fail();
+///
+/// assert_eq!(err.to_string(), "while renaming config");
+/// assert!(err.downcast_ref::<io::Error>().is_some());
+/// ```
+pub trait Context {
+    /// Converts the error into an `anyhow::Error`, annotated with `context`,
+    /// if the value is `Fail`.
+    fn context<C>(self, context: C) -> Fallible<::anyhow::Error>
+    where
+        C: Display + Send + Sync + 'static;
+
+    /// Converts the error into an `anyhow::Error`, annotated with the context
+    /// produced by `f`, if the value is `Fail`.
+    ///
+    /// `f` is only called if the value is `Fail`.
+    fn with_context<C, F>(self, f: F) -> Fallible<::anyhow::Error>
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C;
+}
+
+impl<E> Context for Fallible<E>
+where
+    E: Into<::anyhow::Error>,
+{
+    #[inline]
+    fn context<C>(self, context: C) -> Fallible<::anyhow::Error>
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.map(|err| err.into().context(context))
+    }
+
+    #[inline]
+    fn with_context<C, F>(self, f: F) -> Fallible<::anyhow::Error>
+    where
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Success => Success,
+            Fail(err) => Fail(err.into().context(f())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    use super::Context;
+    use crate::{
+        fallible_try,
+        Fallible::{self, Fail},
+    };
+
+    fn write_to_disk() -> Fallible<io::Error> {
+        Fail(io::Error::other("disk is full"))
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn residual_conversion() {
+        fn save() -> anyhow::Result<u32> {
+            write_to_disk()?;
+            Ok(10)
+        }
+
+        let err = save().unwrap_err().downcast::<io::Error>().unwrap();
+        assert_eq!(err.to_string(), "disk is full");
+    }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn save() -> anyhow::Result<u32> {
+            fallible_try!(write_to_disk());
+            Ok(10)
+        }
+
+        let err = save().unwrap_err().downcast::<io::Error>().unwrap();
+        assert_eq!(err.to_string(), "disk is full");
+    }
+
+    #[test]
+    fn result_conversion() {
+        let result: anyhow::Result<()> = write_to_disk().context("while saving").into();
+        assert_eq!(result.unwrap_err().to_string(), "while saving");
+
+        let outcome: Fallible<anyhow::Error> = anyhow::Result::Ok(()).into();
+        assert!(outcome.is_successful());
+    }
+
+    #[test]
+    fn context_on_anyhow_error() {
+        let err = write_to_disk()
+            .context("while writing")
+            .with_context(|| "while saving")
+            .unwrap_fail();
+
+        assert_eq!(err.to_string(), "while saving");
+        assert_eq!(
+            err.chain().map(ToString::to_string).collect::<Vec<_>>(),
+            ["while saving", "while writing", "disk is full"]
+        );
+    }
+}
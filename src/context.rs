@@ -0,0 +1,208 @@
+use core::fmt::{self, Debug, Display};
+
+use crate::Fallible::{self, Fail, Success};
+
+/// An error `E` annotated with a `context` describing what was being attempted
+/// when the error occurred.
+///
+/// Contexts can be layered by annotating an already annotated error, building up
+/// a chain of `Contextual` errors. Displaying a `Contextual` error only shows its
+/// outermost context, while the alternate format `{:#}` lists the entire chain,
+/// separated by colons.
+///
+/// When the `std` feature is enabled, `Contextual` implements [`std::error::Error`],
+/// exposing the annotated error as its [`source`](std::error::Error::source). Error
+/// reporters which walk the chain of sources, such as `anyhow`, therefore list each
+/// context exactly once.
+///
+/// ```rust
+/// # use fallible_option::{Context, Fallible::{self, Fail, Success}};
+/// let fail = Fail("permission denied")
+///     .context("while renaming config")
+///     .context("while saving settings");
+///
+/// let err = fail.unwrap_fail();
+/// assert_eq!(format!("{err}"), "while saving settings");
+/// assert_eq!(
+///     format!("{err:#}"),
+///     "while saving settings: while renaming config: permission denied"
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Contextual<C, E> {
+    context: C,
+    error: E,
+}
+
+impl<C, E> Contextual<C, E> {
+    /// Annotates `error` with `context`.
+    #[inline]
+    pub fn new(context: C, error: E) -> Self {
+        Contextual { context, error }
+    }
+
+    /// Borrows the context describing the error.
+    #[inline]
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Borrows the annotated error.
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Discards the context, returning the annotated error.
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<C, E> Display for Contextual<C, E>
+where
+    C: Display,
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#}: {:#}", self.context, self.error)
+        } else {
+            Display::fmt(&self.context, f)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C, E> std::error::Error for Contextual<C, E>
+where
+    C: Display + Debug,
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Extension trait for annotating the error of a `Fallible` with context.
+///
+/// ```rust
+/// # use fallible_option::{Context, Contextual, Fallible::{self, Fail, Success}};
+/// fn rename_config() -> Fallible<&'static str> {
+///     Fail("permission denied")
+/// }
+///
+/// let fail = rename_config().with_context(|| format!("while renaming {}", "app.toml"));
+///
+/// assert_eq!(
+///     fail,
+///     Fail(Contextual::new("while renaming app.toml".to_owned(), "permission denied"))
+/// );
+/// ```
+pub trait Context<E> {
+    /// Annotates the error with `context`, if the value is `Fail`.
+    fn context<C>(self, context: C) -> Fallible<Contextual<C, E>>
+    where
+        C: Display;
+
+    /// Annotates the error with the context produced by `f`, if the value is `Fail`.
+    ///
+    /// `f` is only called if the value is `Fail`.
+    fn with_context<C, F>(self, f: F) -> Fallible<Contextual<C, E>>
+    where
+        C: Display,
+        F: FnOnce() -> C;
+}
+
+impl<E> Context<E> for Fallible<E> {
+    #[inline]
+    fn context<C>(self, context: C) -> Fallible<Contextual<C, E>>
+    where
+        C: Display,
+    {
+        self.map(|error| Contextual::new(context, error))
+    }
+
+    #[inline]
+    fn with_context<C, F>(self, f: F) -> Fallible<Contextual<C, E>>
+    where
+        C: Display,
+        F: FnOnce() -> C,
+    {
+        match self {
+            Success => Success,
+            Fail(error) => Fail(Contextual::new(f(), error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::{
+        Context, Contextual,
+        Fallible::{self, Fail, Success},
+    };
+
+    #[test]
+    fn with_context_is_lazy() {
+        let called = Cell::new(false);
+        let outcome = Success::<&str>.with_context(|| {
+            called.set(true);
+            "unused"
+        });
+
+        assert_eq!(outcome, Success);
+        assert!(!called.get());
+    }
+
+    #[test]
+    fn context_chain() {
+        let outcome: Fallible<_> = Fail("inner").context("middle").context("outer");
+
+        let err = outcome.unwrap_fail();
+        assert_eq!(err.context(), &"outer");
+        assert_eq!(err.error(), &Contextual::new("middle", "inner"));
+        assert_eq!(err.into_error().into_error(), "inner");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn error_source() {
+        use std::error::Error;
+        use std::io;
+        use std::string::ToString;
+
+        let err = Fail(io::Error::from(io::ErrorKind::NotFound))
+            .context("while renaming config")
+            .unwrap_fail();
+
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.to_string(),
+            io::Error::from(io::ErrorKind::NotFound).to_string()
+        );
+        assert_eq!(err.to_string(), "while renaming config");
+    }
+
+    #[test]
+    #[cfg(feature = "anyhow")]
+    fn reported_chain() {
+        use std::format;
+        use std::io;
+
+        let err = Fail(io::Error::other("disk full"))
+            .context("while writing")
+            .context("while saving")
+            .unwrap_fail();
+
+        let report = anyhow::Error::new(err);
+        assert_eq!(format!("{report}"), "while saving");
+        assert_eq!(
+            format!("{report:#}"),
+            "while saving: while writing: disk full"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::boxed::Box;
+use std::error::Error;
+
+use crate::Fallible;
+
+/// The following functions are only available if the `std` feature is enabled,
+/// and the generic parameter `E` implements [`Error`]
+impl<E> Fallible<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Maps a `Fallible<E>` to a `Fallible<Box<dyn Error + Send + Sync>>` by boxing
+    /// the contained error, erasing its type.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct DiskFull;
+    ///
+    /// impl fmt::Display for DiskFull {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("disk is full")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for DiskFull {}
+    ///
+    /// let fail = Fail(DiskFull).into_boxed_error();
+    ///
+    /// assert_eq!(fail.to_string(), "disk is full");
+    /// assert!(fail.unwrap_fail().is::<DiskFull>());
+    /// ```
+    #[inline]
+    pub fn into_boxed_error(self) -> Fallible<Box<dyn Error + Send + Sync>> {
+        self.map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use core::fmt;
+    use std::error::Error;
+
+    use crate::{
+        fallible_try,
+        Fallible::{self, Fail, Success},
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct DiskFull;
+
+    impl fmt::Display for DiskFull {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("disk is full")
+        }
+    }
+
+    impl Error for DiskFull {}
+
+    fn write_to_disk() -> Fallible<DiskFull> {
+        Fail(DiskFull)
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn boxed_error_residual_conversion() {
+        fn outer_error() -> Result<u32, Box<dyn Error>> {
+            Fallible::<DiskFull>::Success?;
+            write_to_disk()?;
+            Ok(10)
+        }
+
+        let err = outer_error().unwrap_err();
+        assert_eq!(err.downcast_ref::<DiskFull>(), Some(&DiskFull));
+    }
+
+    #[test]
+    fn boxed_error_fallible_try_conversion() {
+        fn outer_error() -> Result<u32, Box<dyn Error + Send + Sync>> {
+            fallible_try!(write_to_disk());
+            Ok(10)
+        }
+
+        let err = outer_error().unwrap_err();
+        assert_eq!(err.downcast_ref::<DiskFull>(), Some(&DiskFull));
+    }
+
+    #[test]
+    fn boxed_error_display() {
+        let fail = write_to_disk().into_boxed_error();
+
+        assert_eq!(std::format!("{fail}"), "disk is full");
+    }
+
+    #[test]
+    fn boxed_success() {
+        assert!(Success::<DiskFull>.into_boxed_error().is_successful());
+    }
+}
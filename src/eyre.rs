@@ -0,0 +1,154 @@
+//! Interoperability with [`eyre`](https://docs.rs/eyre).
+//!
+//! A `Fallible<eyre::Report>` converts to and from an `eyre::Result<()>` using `From`,
+//! and [`fallible_try!`](crate::fallible_try) propagates any `Fallible<E>` whose error is
+//! an [`std::error::Error`] out of a function returning `eyre::Result<T>`.
+//!
+//! Reports are created through the installed [`eyre::EyreHandler`], so a handler such as
+//! `color-eyre` installed by the application also applies to failures converted from
+//! a `Fallible`.
+//!
+//! ```rust
+//! # use fallible_option::{eyre::WrapErr, Fallible::{self, Fail}};
+//! use std::io;
+//!
+//! fn rename() -> Fallible<io::Error> {
+//!     Fail(io::Error::from(io::ErrorKind::PermissionDenied))
+//! }
+//!
+//! fn save() -> eyre::Result<()> {
+//!     rename().wrap_err("while saving config").into()
+//! }
+//!
+//! let report = save().unwrap_err();
+//! assert_eq!(report.to_string(), "while saving config");
+//! assert_eq!(report.root_cause().to_string(), "permission denied");
+//! ```
+
+use core::fmt::{Debug, Display};
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Extension trait for wrapping the error of a `Fallible` with a message,
+/// producing a `Fallible<eyre::Report>`.
+///
+/// This is the `eyre` counterpart to [`crate::Context`].
+///
+/// ```rust
+/// # use fallible_option::Fallible::{self, Fail, Success};
+/// use fallible_option::eyre::WrapErr;
+/// use std::io;
+///
+/// fn rename() -> Fallible<io::Error> {
+///     Fail(io::Error::from(io::ErrorKind::PermissionDenied))
+/// }
+///
+/// let err = rename().wrap_err("while renaming config").unwrap_fail();
+///
+/// assert_eq!(err.to_string(), "while renaming config");
+/// assert!(err.downcast_ref::<io::Error>().is_some());
+/// ```
+pub trait WrapErr {
+    /// Converts the error into an `eyre::Report`, wrapped with `msg`,
+    /// if the value is `Fail`.
+    fn wrap_err<D>(self, msg: D) -> Fallible<::eyre::Report>
+    where
+        D: Display + Debug + Send + Sync + 'static;
+
+    /// Converts the error into an `eyre::Report`, wrapped with the message
+    /// produced by `f`, if the value is `Fail`.
+    ///
+    /// `f` is only called if the value is `Fail`.
+    fn wrap_err_with<D, F>(self, f: F) -> Fallible<::eyre::Report>
+    where
+        D: Display + Debug + Send + Sync + 'static,
+        F: FnOnce() -> D;
+}
+
+impl<E> WrapErr for Fallible<E>
+where
+    E: Into<::eyre::Report>,
+{
+    #[inline]
+    fn wrap_err<D>(self, msg: D) -> Fallible<::eyre::Report>
+    where
+        D: Display + Debug + Send + Sync + 'static,
+    {
+        self.map(|err| err.into().wrap_err(msg))
+    }
+
+    #[inline]
+    fn wrap_err_with<D, F>(self, f: F) -> Fallible<::eyre::Report>
+    where
+        D: Display + Debug + Send + Sync + 'static,
+        F: FnOnce() -> D,
+    {
+        match self {
+            Success => Success,
+            Fail(err) => Fail(err.into().wrap_err(f())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    use super::WrapErr;
+    use crate::{
+        fallible_try,
+        Fallible::{self, Fail},
+    };
+
+    fn write_to_disk() -> Fallible<io::Error> {
+        Fail(io::Error::other("disk is full"))
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn residual_conversion() {
+        fn save() -> eyre::Result<u32> {
+            write_to_disk()?;
+            Ok(10)
+        }
+
+        let err = save().unwrap_err().downcast::<io::Error>().unwrap();
+        assert_eq!(err.to_string(), "disk is full");
+    }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn save() -> eyre::Result<u32> {
+            fallible_try!(write_to_disk());
+            Ok(10)
+        }
+
+        let err = save().unwrap_err().downcast::<io::Error>().unwrap();
+        assert_eq!(err.to_string(), "disk is full");
+    }
+
+    #[test]
+    fn result_conversion() {
+        let result: eyre::Result<()> = write_to_disk().wrap_err("while saving").into();
+        assert_eq!(result.unwrap_err().to_string(), "while saving");
+
+        let outcome: Fallible<eyre::Report> = eyre::Result::Ok(()).into();
+        assert!(outcome.is_successful());
+    }
+
+    #[test]
+    fn wrap_err_on_report() {
+        let report = write_to_disk()
+            .wrap_err("while writing")
+            .wrap_err_with(|| "while saving")
+            .unwrap_fail();
+
+        assert_eq!(report.to_string(), "while saving");
+        assert_eq!(
+            report.chain().map(ToString::to_string).collect::<Vec<_>>(),
+            ["while saving", "while writing", "disk is full"]
+        );
+    }
+}
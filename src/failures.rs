@@ -0,0 +1,271 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Accumulates the errors of many `Fallible<E>` outcomes, instead of stopping at the first.
+///
+/// Errors are collected into the container `C`, which defaults to a `Vec<E>`, and the
+/// accumulated outcome can be converted back into a `Fallible<C>` once all outcomes have
+/// been gathered, which is `Success` only if no failures were collected.
+///
+/// ```rust
+/// # use fallible_option::{Failures, Fallible::{self, Fail, Success}};
+/// fn validate_number(x: u32) -> Fallible<&'static str> {
+///     match x {
+///         0 ..= 9 => Fail("number is too small"),
+///         10..=30 => Success,
+///         31..    => Fail("number is too large")
+///     }
+/// }
+///
+/// let failures: Failures<_> = [5, 20, 40].into_iter().map(validate_number).collect();
+///
+/// assert_eq!(
+///     failures.into_fallible(),
+///     Fail(vec!["number is too small", "number is too large"])
+/// );
+/// ```
+pub struct Failures<E, C = Vec<E>> {
+    errors: C,
+    len: usize,
+    _error: PhantomData<E>,
+}
+
+impl<E, C> Failures<E, C>
+where
+    C: Default,
+{
+    /// Constructs an empty accumulator.
+    #[inline]
+    pub fn new() -> Self {
+        Failures {
+            errors: C::default(),
+            len: 0,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<E, C> Failures<E, C> {
+    /// Returns the number of failures collected so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no failures have been collected.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Borrows the container of collected errors.
+    #[inline]
+    pub fn errors(&self) -> &C {
+        &self.errors
+    }
+
+    /// Converts the accumulator into a `Fallible<C>`, which is `Success` if no
+    /// failures were collected, or `Fail` containing all the collected errors.
+    ///
+    /// ```rust
+    /// # use fallible_option::{Failures, Fallible::{self, Fail, Success}};
+    /// let mut failures = Failures::<&str>::new();
+    /// failures.push(Success);
+    ///
+    /// assert_eq!(failures.into_fallible(), Success);
+    /// ```
+    #[inline]
+    pub fn into_fallible(self) -> Fallible<C> {
+        if self.is_empty() {
+            Success
+        } else {
+            Fail(self.errors)
+        }
+    }
+}
+
+impl<E, C> Failures<E, C>
+where
+    C: Extend<E>,
+{
+    /// Collects the error of `outcome`, if it is a `Fail`.
+    ///
+    /// ```rust
+    /// # use fallible_option::{Failures, Fallible::{self, Fail, Success}};
+    /// let mut failures = Failures::new();
+    /// failures.push(Fail("first"));
+    /// failures.push(Success);
+    /// failures.push(Fail("third"));
+    ///
+    /// assert_eq!(failures.len(), 2);
+    /// assert_eq!(failures.into_fallible(), Fail(vec!["first", "third"]));
+    /// ```
+    #[inline]
+    pub fn push(&mut self, outcome: Fallible<E>) {
+        if let Fail(e) = outcome {
+            self.errors.extend(Some(e));
+            self.len += 1;
+        }
+    }
+}
+
+// `E` only appears in `PhantomData`, so these are implemented by hand to avoid
+// requiring the same traits of `E` as derives would.
+impl<E, C> Clone for Failures<E, C>
+where
+    C: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Failures {
+            errors: self.errors.clone(),
+            len: self.len,
+            _error: PhantomData,
+        }
+    }
+}
+
+impl<E, C> PartialEq for Failures<E, C>
+where
+    C: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.errors == other.errors
+    }
+}
+
+impl<E, C> Eq for Failures<E, C> where C: Eq {}
+
+impl<E, C> Debug for Failures<E, C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Failures")
+            .field("errors", &self.errors)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<E, C> Default for Failures<E, C>
+where
+    C: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Failures::new()
+    }
+}
+
+impl<E, C> Extend<Fallible<E>> for Failures<E, C>
+where
+    C: Extend<E>,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = Fallible<E>>>(&mut self, iter: I) {
+        for outcome in iter {
+            self.push(outcome);
+        }
+    }
+}
+
+impl<E, C> FromIterator<Fallible<E>> for Failures<E, C>
+where
+    C: Default + Extend<E>,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Fallible<E>>>(iter: I) -> Self {
+        let mut failures = Failures::new();
+        failures.extend(iter);
+        failures
+    }
+}
+
+impl<E, C> From<Failures<E, C>> for Fallible<C> {
+    #[inline]
+    fn from(value: Failures<E, C>) -> Self {
+        value.into_fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::format;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::{
+        fallible_try, Failures,
+        Fallible::{self, Fail, Success},
+    };
+
+    fn fails_if_odd(n: u32) -> Fallible<u32> {
+        if n % 2 == 1 {
+            Fail(n)
+        } else {
+            Success
+        }
+    }
+
+    #[test]
+    fn collects_every_failure() {
+        let failures: Failures<u32> = (0..6).map(fails_if_odd).collect();
+
+        assert_eq!(failures.len(), 3);
+        assert_eq!(failures.into_fallible(), Fail(vec![1, 3, 5]));
+    }
+
+    #[test]
+    fn custom_container() {
+        let failures: Failures<u32, BTreeSet<u32>> =
+            [3, 1, 3, 2].into_iter().map(fails_if_odd).collect();
+
+        assert_eq!(failures.len(), 3);
+        assert_eq!(failures.errors().len(), 2);
+    }
+
+    #[test]
+    fn traits_do_not_require_error_traits() {
+        // Neither `Clone`, `PartialEq` nor `Debug`.
+        struct Opaque;
+
+        let failures: Failures<Opaque, usize> = Failures::new();
+        let copy = failures.clone();
+
+        assert_eq!(failures, copy);
+        assert_eq!(format!("{copy:?}"), "Failures { errors: 0, len: 0 }");
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn residual_conversion() {
+        fn validate_all() -> Fallible<Vec<u32>> {
+            (0..4)
+                .map(fails_if_odd)
+                .collect::<Failures<u32>>()
+                .into_fallible()?;
+            Success
+        }
+
+        assert_eq!(validate_all(), Fail(vec![1, 3]));
+    }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn validate_all() -> Fallible<Vec<u32>> {
+            fallible_try!((0..4)
+                .map(fails_if_odd)
+                .collect::<Failures<u32>>()
+                .into_fallible());
+            Success
+        }
+
+        assert_eq!(validate_all(), Fail(vec![1, 3]));
+    }
+}
@@ -0,0 +1,359 @@
+//! Filesystem operations which produce no output, returning `Fallible<io::Error>`.
+//!
+//! Each function wraps its counterpart in [`std::fs`], with the same arguments and
+//! error conditions, but communicates through its return type that it can only fail:
+//!
+//! ```rust
+//! # use fallible_option::{fallible_try, fs, Fallible::{self, Success}};
+//! use std::{io, path::Path};
+//!
+//! fn save(dir: &Path, config: &str) -> Fallible<io::Error> {
+//!     fallible_try!(fs::write(dir.join("config.toml.tmp"), config));
+//!     fallible_try!(fs::rename(dir.join("config.toml.tmp"), dir.join("config.toml")));
+//!
+//!     Success
+//! }
+//!
+//! let dir = std::env::temp_dir().join("fallible-option-fs-example");
+//! assert!(fs::create_dir_all(&dir).is_successful());
+//! assert!(save(&dir, "threads = 4").is_successful());
+//! # assert!(fs::remove_dir_all(&dir).is_successful());
+//! ```
+//!
+//! The `_if_exists` and `_if_missing` variants are idempotent, and treat the error
+//! produced when the operation has effectively already been performed as `Success`.
+
+use std::fs::{self, DirBuilder, File, FileTimes, Permissions};
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::{
+    io::ErrorKindExt,
+    Fallible::{self, Fail, Success},
+};
+
+/// Renames a file or directory, replacing the destination if it already exists.
+///
+/// See [`std::fs::rename`].
+#[inline]
+pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> Fallible<io::Error> {
+    fs::rename(from, to).into()
+}
+
+/// Copies the contents and permissions of one file to another, discarding the number
+/// of bytes copied.
+///
+/// See [`std::fs::copy`].
+#[inline]
+pub fn copy<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> Fallible<io::Error> {
+    fs::copy(from, to).into()
+}
+
+/// Creates a new hard link on the filesystem.
+///
+/// See [`std::fs::hard_link`].
+#[inline]
+pub fn hard_link<P: AsRef<Path>, Q: AsRef<Path>>(original: P, link: Q) -> Fallible<io::Error> {
+    fs::hard_link(original, link).into()
+}
+
+/// Writes `contents` to a file, creating it if it does not exist, and truncating it if it does.
+///
+/// See [`std::fs::write`].
+#[inline]
+pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> Fallible<io::Error> {
+    fs::write(path, contents).into()
+}
+
+/// Removes a file.
+///
+/// See [`std::fs::remove_file`].
+#[inline]
+pub fn remove_file<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    fs::remove_file(path).into()
+}
+
+/// Removes an empty directory.
+///
+/// See [`std::fs::remove_dir`].
+#[inline]
+pub fn remove_dir<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    fs::remove_dir(path).into()
+}
+
+/// Removes a directory, after removing all of its contents.
+///
+/// See [`std::fs::remove_dir_all`].
+#[inline]
+pub fn remove_dir_all<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    fs::remove_dir_all(path).into()
+}
+
+/// Creates a new, empty directory.
+///
+/// See [`std::fs::create_dir`].
+#[inline]
+pub fn create_dir<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    fs::create_dir(path).into()
+}
+
+/// Creates a directory, along with all of its missing parents.
+///
+/// See [`std::fs::create_dir_all`].
+#[inline]
+pub fn create_dir_all<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    fs::create_dir_all(path).into()
+}
+
+/// Creates a new directory at `path`, with the options configured on `builder`.
+///
+/// See [`DirBuilder::create`].
+#[inline]
+pub fn create_dir_with<P: AsRef<Path>>(builder: &DirBuilder, path: P) -> Fallible<io::Error> {
+    builder.create(path).into()
+}
+
+/// Changes the permissions of a file or directory.
+///
+/// See [`std::fs::set_permissions`].
+#[inline]
+pub fn set_permissions<P: AsRef<Path>>(path: P, permissions: Permissions) -> Fallible<io::Error> {
+    fs::set_permissions(path, permissions).into()
+}
+
+/// Flushes all data and metadata of `file` to the filesystem.
+///
+/// See [`File::sync_all`].
+#[inline]
+pub fn sync_all(file: &File) -> Fallible<io::Error> {
+    file.sync_all().into()
+}
+
+/// Flushes the data of `file` to the filesystem, without necessarily flushing its metadata.
+///
+/// See [`File::sync_data`].
+#[inline]
+pub fn sync_data(file: &File) -> Fallible<io::Error> {
+    file.sync_data().into()
+}
+
+/// Truncates or extends `file` to `size` bytes.
+///
+/// See [`File::set_len`].
+#[inline]
+pub fn set_len(file: &File, size: u64) -> Fallible<io::Error> {
+    file.set_len(size).into()
+}
+
+/// Changes the permissions of an open `file`.
+///
+/// See [`File::set_permissions`].
+#[inline]
+pub fn set_file_permissions(file: &File, permissions: Permissions) -> Fallible<io::Error> {
+    file.set_permissions(permissions).into()
+}
+
+/// Changes the modification time of `file`.
+///
+/// See [`File::set_modified`].
+#[inline]
+pub fn set_modified(file: &File, time: SystemTime) -> Fallible<io::Error> {
+    file.set_modified(time).into()
+}
+
+/// Changes the access and modification times of `file`.
+///
+/// See [`File::set_times`].
+#[inline]
+pub fn set_times(file: &File, times: FileTimes) -> Fallible<io::Error> {
+    file.set_times(times).into()
+}
+
+/// Acquires an exclusive lock on `file`, blocking until it can be acquired.
+///
+/// See [`File::lock`].
+#[inline]
+pub fn lock(file: &File) -> Fallible<io::Error> {
+    file.lock().into()
+}
+
+/// Acquires a shared lock on `file`, blocking until it can be acquired.
+///
+/// See [`File::lock_shared`].
+#[inline]
+pub fn lock_shared(file: &File) -> Fallible<io::Error> {
+    file.lock_shared().into()
+}
+
+/// Releases any lock held on `file`.
+///
+/// See [`File::unlock`].
+#[inline]
+pub fn unlock(file: &File) -> Fallible<io::Error> {
+    file.unlock().into()
+}
+
+/// Removes a file, succeeding if it does not exist.
+///
+/// ```rust
+/// # use fallible_option::fs;
+/// let path = std::env::temp_dir().join("fallible-option-does-not-exist");
+///
+/// assert!(fs::remove_file(&path).is_fail());
+/// assert!(fs::remove_file_if_exists(&path).is_successful());
+/// ```
+#[inline]
+pub fn remove_file_if_exists<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    remove_file(path).ignore_kind(ErrorKind::NotFound)
+}
+
+/// Removes an empty directory, succeeding if it does not exist.
+#[inline]
+pub fn remove_dir_if_exists<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    remove_dir(path).ignore_kind(ErrorKind::NotFound)
+}
+
+/// Removes a directory and all of its contents, succeeding if it does not exist.
+#[inline]
+pub fn remove_dir_all_if_exists<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    remove_dir_all(path).ignore_kind(ErrorKind::NotFound)
+}
+
+/// Creates a new, empty directory, succeeding if a directory already exists at `path`.
+///
+/// Unlike [`create_dir_all`], this fails if the parent directory does not exist, and
+/// also fails if `path` exists, but is not a directory.
+#[inline]
+pub fn create_dir_if_missing<P: AsRef<Path>>(path: P) -> Fallible<io::Error> {
+    let path = path.as_ref();
+
+    match create_dir(path) {
+        Fail(err) if err.kind() == ErrorKind::AlreadyExists && path.is_dir() => Success,
+        outcome => outcome,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::format;
+    use std::fs::FileTimes;
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use crate::{fallible_try, Fallible};
+
+    /// Returns a directory unique to the calling test, which does not yet exist.
+    fn scratch_dir(test: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("fallible-option-{}-{test}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn file_lifecycle() {
+        let dir = scratch_dir("file-lifecycle");
+
+        fn run(dir: &std::path::Path) -> Fallible<std::io::Error> {
+            fallible_try!(super::create_dir_all(dir.join("nested")));
+            fallible_try!(super::write(dir.join("a"), "contents"));
+            fallible_try!(super::copy(dir.join("a"), dir.join("b")));
+            fallible_try!(super::rename(dir.join("b"), dir.join("nested/c")));
+            fallible_try!(super::hard_link(dir.join("a"), dir.join("d")));
+
+            let file = std::fs::OpenOptions::new()
+                .write(true)
+                .open(dir.join("d"))
+                .unwrap();
+            fallible_try!(super::set_len(&file, 3));
+            fallible_try!(super::sync_all(&file));
+            fallible_try!(super::sync_data(&file));
+
+            let permissions = std::fs::metadata(dir.join("a")).unwrap().permissions();
+            fallible_try!(super::set_file_permissions(&file, permissions.clone()));
+            super::set_permissions(dir.join("a"), permissions)
+        }
+
+        assert!(run(&dir).is_successful());
+        assert_eq!(std::fs::read_to_string(dir.join("a")).unwrap(), "con");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("nested/c")).unwrap(),
+            "contents"
+        );
+
+        assert!(super::remove_file(dir.join("d")).is_successful());
+        assert!(super::remove_dir(&dir).is_fail());
+        assert!(super::remove_dir_all(&dir).is_successful());
+    }
+
+    #[test]
+    fn file_times() {
+        let dir = scratch_dir("file-times");
+        assert!(super::create_dir_with(&std::fs::DirBuilder::new(), &dir).is_successful());
+        assert!(super::create_dir_with(&std::fs::DirBuilder::new(), &dir).is_fail());
+
+        let path = dir.join("file");
+        let file = std::fs::File::create(&path).unwrap();
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+
+        assert!(super::set_modified(&file, epoch).is_successful());
+        assert_eq!(file.metadata().unwrap().modified().unwrap(), epoch);
+
+        let later = epoch + Duration::from_secs(60);
+        let times = FileTimes::new().set_accessed(later).set_modified(later);
+        assert!(super::set_times(&file, times).is_successful());
+        assert_eq!(file.metadata().unwrap().modified().unwrap(), later);
+
+        assert!(super::remove_dir_all(&dir).is_successful());
+    }
+
+    #[test]
+    fn file_locks() {
+        let dir = scratch_dir("file-locks");
+        assert!(super::create_dir_all(&dir).is_successful());
+
+        let path = dir.join("lock");
+        let file = std::fs::File::create(&path).unwrap();
+        let other = std::fs::File::open(&path).unwrap();
+
+        assert!(super::lock(&file).is_successful());
+        assert!(other.try_lock_shared().is_err());
+        assert!(super::unlock(&file).is_successful());
+
+        assert!(super::lock_shared(&file).is_successful());
+        assert!(super::lock_shared(&other).is_successful());
+        assert!(super::unlock(&other).is_successful());
+        assert!(super::unlock(&file).is_successful());
+
+        assert!(super::remove_dir_all(&dir).is_successful());
+    }
+
+    #[test]
+    fn idempotent_variants() {
+        let dir = scratch_dir("idempotent");
+
+        assert!(super::create_dir_if_missing(&dir).is_successful());
+        assert!(super::create_dir_if_missing(&dir).is_successful());
+        assert!(super::create_dir(&dir).is_fail());
+
+        let file = dir.join("file");
+        assert!(super::write(&file, "").is_successful());
+        assert_eq!(
+            super::create_dir_if_missing(&file).map(|err| err.kind()),
+            Fallible::Fail(ErrorKind::AlreadyExists)
+        );
+
+        assert!(super::remove_file_if_exists(&file).is_successful());
+        assert!(super::remove_file_if_exists(&file).is_successful());
+        assert_eq!(
+            super::remove_file(&file).map(|err| err.kind()),
+            Fallible::Fail(ErrorKind::NotFound)
+        );
+
+        assert!(super::remove_dir_if_exists(&dir).is_successful());
+        assert!(super::remove_dir_if_exists(&dir).is_successful());
+        assert!(super::remove_dir_all_if_exists(&dir).is_successful());
+    }
+}
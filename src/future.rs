@@ -0,0 +1,265 @@
+//! Combinators for futures which resolve to a [`Fallible`].
+//!
+//! Everything in this module is written against [`core::future`], and so is available
+//! in `no_std` environments, with the exception of [`try_join_all`], which requires
+//! the `alloc` feature.
+//!
+//! ```rust
+//! # use fallible_option::{future::FallibleFutureExt, Fallible::{self, Fail, Success}};
+//! # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+//! #     let mut future = std::pin::pin!(future);
+//! #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+//! #     loop {
+//! #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+//! #             return output;
+//! #         }
+//! #     }
+//! # }
+//! async fn rename() -> Fallible<&'static str> {
+//!     Success
+//! }
+//!
+//! async fn flush() -> Fallible<&'static str> {
+//!     Fail("disk is full")
+//! }
+//!
+//! let outcome = rename()
+//!     .and_then_fallible(flush)
+//!     .map_fail(|err| format!("failed to save: {err}"));
+//!
+//! assert_eq!(block_on(outcome), Fail("failed to save: disk is full".to_owned()));
+//! ```
+
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Extension trait for futures which resolve to a `Fallible<E>`.
+///
+/// This trait is implemented for every `Future<Output = Fallible<E>>`.
+pub trait FallibleFutureExt<E>: Future<Output = Fallible<E>> {
+    /// Maps the error of the resolved `Fallible<E>` by applying `f` to it,
+    /// equivalent to calling [`Fallible::map`] on the output.
+    fn map_fail<U, F>(self, f: F) -> impl Future<Output = Fallible<U>>
+    where
+        Self: Sized,
+        F: FnOnce(E) -> U,
+    {
+        async move { self.await.map(f) }
+    }
+
+    /// Awaits the future returned by `f` if this future resolves to `Success`,
+    /// otherwise resolves to the `Fail` of this future without calling `f`.
+    fn and_then_fallible<Fut, F>(self, f: F) -> impl Future<Output = Fallible<E>>
+    where
+        Self: Sized,
+        Fut: Future<Output = Fallible<E>>,
+        F: FnOnce() -> Fut,
+    {
+        async move {
+            match self.await {
+                Success => f().await,
+                Fail(e) => Fail(e),
+            }
+        }
+    }
+
+    /// Calls `f` with a reference to the error, if this future resolves to `Fail`,
+    /// equivalent to calling [`Fallible::inspect`] on the output.
+    fn inspect_fail<F>(self, f: F) -> impl Future<Output = Fallible<E>>
+    where
+        Self: Sized,
+        F: FnOnce(&E),
+    {
+        async move { self.await.inspect(f) }
+    }
+
+    /// Converts the output of this future into a `Result<(), E>`,
+    /// equivalent to calling [`Fallible::result`] on the output.
+    fn into_result(self) -> impl Future<Output = Result<(), E>>
+    where
+        Self: Sized,
+    {
+        async move { self.await.result() }
+    }
+}
+
+impl<E, F> FallibleFutureExt<E> for F where F: Future<Output = Fallible<E>> {}
+
+/// Polls both futures concurrently, resolving to the first `Fail` produced by either,
+/// or to `Success` once both have succeeded.
+///
+/// Once either future fails, the other is dropped without being polled again.
+pub async fn try_join<A, B, E>(a: A, b: B) -> Fallible<E>
+where
+    A: Future<Output = Fallible<E>>,
+    B: Future<Output = Fallible<E>>,
+{
+    let mut a = pin!(a);
+    let mut b = pin!(b);
+    let (mut a_done, mut b_done) = (false, false);
+
+    poll_fn(move |cx| {
+        if !a_done {
+            if let Poll::Ready(outcome) = a.as_mut().poll(cx) {
+                if let Fail(e) = outcome {
+                    return Poll::Ready(Fail(e));
+                }
+                a_done = true;
+            }
+        }
+
+        if !b_done {
+            if let Poll::Ready(outcome) = b.as_mut().poll(cx) {
+                if let Fail(e) = outcome {
+                    return Poll::Ready(Fail(e));
+                }
+                b_done = true;
+            }
+        }
+
+        if a_done && b_done {
+            Poll::Ready(Success)
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Polls every future concurrently, resolving to the first `Fail` produced by any of them,
+/// or to `Success` once all of them have succeeded.
+///
+/// Once any future fails, the remaining futures are dropped without being polled again.
+#[cfg(feature = "alloc")]
+pub async fn try_join_all<I, E>(futures: I) -> Fallible<E>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Fallible<E>>,
+{
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+
+    let mut pending: Vec<_> = futures.into_iter().map(Box::pin).collect();
+
+    poll_fn(move |cx| {
+        let mut index = 0;
+        while index < pending.len() {
+            match pending[index].as_mut().poll(cx) {
+                Poll::Ready(Fail(e)) => return Poll::Ready(Fail(e)),
+                Poll::Ready(Success) => drop(pending.swap_remove(index)),
+                Poll::Pending => index += 1,
+            }
+        }
+
+        if pending.is_empty() {
+            Poll::Ready(Success)
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::future::{poll_fn, Future};
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    use super::{try_join, FallibleFutureExt};
+    use crate::Fallible::{self, Fail, Success};
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// Resolves to `outcome` after having been polled `delay` times.
+    fn delayed<E>(delay: usize, outcome: Fallible<E>) -> impl Future<Output = Fallible<E>> {
+        let mut remaining = delay;
+        let mut outcome = Some(outcome);
+
+        poll_fn(move |_| {
+            if remaining == 0 {
+                Poll::Ready(outcome.take().unwrap())
+            } else {
+                remaining -= 1;
+                Poll::Pending
+            }
+        })
+    }
+
+    #[test]
+    fn map_fail() {
+        let outcome = block_on(delayed(1, Fail(2)).map_fail(|n| n * 10));
+
+        assert_eq!(outcome, Fail(20));
+    }
+
+    #[test]
+    fn and_then_fallible_short_circuits() {
+        let called = Cell::new(false);
+        let outcome = block_on(delayed(1, Fail("first")).and_then_fallible(|| {
+            called.set(true);
+            delayed(0, Success)
+        }));
+
+        assert_eq!(outcome, Fail("first"));
+        assert!(!called.get());
+
+        let outcome =
+            block_on(delayed(1, Success).and_then_fallible(|| delayed(1, Fail("second"))));
+        assert_eq!(outcome, Fail("second"));
+    }
+
+    #[test]
+    fn inspect_fail() {
+        let seen = Cell::new(0);
+        let outcome = block_on(delayed(0, Fail(3)).inspect_fail(|n| seen.set(*n)));
+
+        assert_eq!(outcome, Fail(3));
+        assert_eq!(seen.get(), 3);
+    }
+
+    #[test]
+    fn into_result() {
+        assert_eq!(block_on(delayed(0, Fail(3)).into_result()), Err(3));
+        assert_eq!(block_on(delayed(0, Success::<u32>).into_result()), Ok(()));
+    }
+
+    #[test]
+    fn try_join_returns_first_failure() {
+        let outcome = block_on(try_join(delayed(5, Fail("slow")), delayed(2, Fail("fast"))));
+        assert_eq!(outcome, Fail("fast"));
+
+        let outcome = block_on(try_join(delayed(5, Success::<&str>), delayed(2, Success)));
+        assert_eq!(outcome, Success);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_join_all_returns_first_failure() {
+        use super::try_join_all;
+
+        let futures = [
+            delayed(1, Success),
+            delayed(4, Fail(4)),
+            delayed(2, Fail(2)),
+            delayed(3, Success),
+        ];
+        assert_eq!(block_on(try_join_all(futures)), Fail(2));
+
+        let futures = [delayed(1, Success::<u32>), delayed(3, Success)];
+        assert_eq!(block_on(try_join_all(futures)), Success);
+    }
+}
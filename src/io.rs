@@ -0,0 +1,223 @@
+//! Adapters for suppressing or rewriting [`io::Error`]s based on their [`ErrorKind`].
+//!
+//! ```rust
+//! # use fallible_option::{io::ErrorKindExt, Fallible::{self, Success}};
+//! use std::io::ErrorKind;
+//!
+//! let path = std::env::temp_dir().join("fallible-option-io-example");
+//!
+//! // Deleting a file which does not exist is fine.
+//! let outcome = std::fs::remove_file(&path).ignore_kind(ErrorKind::NotFound);
+//!
+//! assert!(outcome.is_successful());
+//! ```
+
+use std::io::{self, ErrorKind};
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Extension trait for inspecting the [`ErrorKind`] of a `Fallible<io::Error>`,
+/// or a `Result<T, io::Error>`.
+///
+/// Every method returns a `Fallible<io::Error>`, discarding the value of an `Ok`.
+pub trait ErrorKindExt {
+    /// Converts a `Fail` whose error is of the given `kind` into `Success`.
+    ///
+    /// ```rust
+    /// # use fallible_option::{io::ErrorKindExt, Fallible::{self, Fail}};
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// let outcome = Fail(io::Error::from(ErrorKind::NotFound));
+    /// assert!(outcome.ignore_kind(ErrorKind::NotFound).is_successful());
+    ///
+    /// let outcome = Fail(io::Error::from(ErrorKind::PermissionDenied));
+    /// assert!(outcome.ignore_kind(ErrorKind::NotFound).is_fail());
+    /// ```
+    fn ignore_kind(self, kind: ErrorKind) -> Fallible<io::Error>;
+
+    /// Converts a `Fail` whose error is of any of the given `kinds` into `Success`.
+    ///
+    /// ```rust
+    /// # use fallible_option::{io::ErrorKindExt, Fallible::{self, Fail}};
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// let transient = [ErrorKind::Interrupted, ErrorKind::WouldBlock];
+    ///
+    /// let outcome = Fail(io::Error::from(ErrorKind::WouldBlock));
+    /// assert!(outcome.ignore_kinds(&transient).is_successful());
+    /// ```
+    fn ignore_kinds(self, kinds: &[ErrorKind]) -> Fallible<io::Error>;
+
+    /// Converts a `Fail` whose error is *not* of the given `kind` into `Success`,
+    /// such that only errors of that kind are propagated.
+    ///
+    /// ```rust
+    /// # use fallible_option::{io::ErrorKindExt, Fallible::{self, Fail}};
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// let outcome = Fail(io::Error::from(ErrorKind::NotFound));
+    /// assert!(outcome.fail_only_on(ErrorKind::PermissionDenied).is_successful());
+    ///
+    /// let outcome = Fail(io::Error::from(ErrorKind::PermissionDenied));
+    /// assert!(outcome.fail_only_on(ErrorKind::PermissionDenied).is_fail());
+    /// ```
+    fn fail_only_on(self, kind: ErrorKind) -> Fallible<io::Error>;
+
+    /// Maps a `Fail` whose error is of the given `kind` by applying `f` to the error,
+    /// leaving errors of any other kind untouched.
+    ///
+    /// ```rust
+    /// # use fallible_option::{io::ErrorKindExt, Fallible::{self, Fail}};
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// let outcome = Fail(io::Error::from(ErrorKind::NotFound))
+    ///     .map_kind(ErrorKind::NotFound, |_| io::Error::other("configuration file is missing"));
+    ///
+    /// assert_eq!(outcome.unwrap_fail().to_string(), "configuration file is missing");
+    /// ```
+    fn map_kind<F>(self, kind: ErrorKind, f: F) -> Fallible<io::Error>
+    where
+        F: FnOnce(io::Error) -> io::Error;
+}
+
+impl ErrorKindExt for Fallible<io::Error> {
+    #[inline]
+    fn ignore_kind(self, kind: ErrorKind) -> Fallible<io::Error> {
+        self.filter(|err| err.kind() != kind)
+    }
+
+    #[inline]
+    fn ignore_kinds(self, kinds: &[ErrorKind]) -> Fallible<io::Error> {
+        self.filter(|err| !kinds.contains(&err.kind()))
+    }
+
+    #[inline]
+    fn fail_only_on(self, kind: ErrorKind) -> Fallible<io::Error> {
+        self.filter(|err| err.kind() == kind)
+    }
+
+    #[inline]
+    fn map_kind<F>(self, kind: ErrorKind, f: F) -> Fallible<io::Error>
+    where
+        F: FnOnce(io::Error) -> io::Error,
+    {
+        match self {
+            Fail(err) if err.kind() == kind => Fail(f(err)),
+            Fail(err) => Fail(err),
+            Success => Success,
+        }
+    }
+}
+
+impl<T> ErrorKindExt for Result<T, io::Error> {
+    #[inline]
+    fn ignore_kind(self, kind: ErrorKind) -> Fallible<io::Error> {
+        Fallible::from(self).ignore_kind(kind)
+    }
+
+    #[inline]
+    fn ignore_kinds(self, kinds: &[ErrorKind]) -> Fallible<io::Error> {
+        Fallible::from(self).ignore_kinds(kinds)
+    }
+
+    #[inline]
+    fn fail_only_on(self, kind: ErrorKind) -> Fallible<io::Error> {
+        Fallible::from(self).fail_only_on(kind)
+    }
+
+    #[inline]
+    fn map_kind<F>(self, kind: ErrorKind, f: F) -> Fallible<io::Error>
+    where
+        F: FnOnce(io::Error) -> io::Error,
+    {
+        Fallible::from(self).map_kind(kind, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, ErrorKind};
+
+    use super::ErrorKindExt;
+    use crate::{
+        fallible_try,
+        Fallible::{self, Fail, Success},
+    };
+
+    fn fail(kind: ErrorKind) -> Fallible<io::Error> {
+        Fail(io::Error::from(kind))
+    }
+
+    fn kind(outcome: Fallible<io::Error>) -> Option<ErrorKind> {
+        outcome.err().map(io::Error::kind)
+    }
+
+    #[test]
+    fn ignore_kinds() {
+        assert_eq!(
+            kind(fail(ErrorKind::NotFound).ignore_kind(ErrorKind::NotFound)),
+            None
+        );
+        assert_eq!(
+            kind(fail(ErrorKind::Other).ignore_kind(ErrorKind::NotFound)),
+            Some(ErrorKind::Other)
+        );
+        assert_eq!(kind(Success.ignore_kind(ErrorKind::NotFound)), None);
+
+        let kinds = [ErrorKind::NotFound, ErrorKind::AlreadyExists];
+        assert_eq!(
+            kind(fail(ErrorKind::AlreadyExists).ignore_kinds(&kinds)),
+            None
+        );
+        assert_eq!(
+            kind(fail(ErrorKind::Other).ignore_kinds(&kinds)),
+            Some(ErrorKind::Other)
+        );
+    }
+
+    #[test]
+    fn fail_only_on() {
+        assert_eq!(
+            kind(fail(ErrorKind::NotFound).fail_only_on(ErrorKind::NotFound)),
+            Some(ErrorKind::NotFound)
+        );
+        assert_eq!(
+            kind(fail(ErrorKind::Other).fail_only_on(ErrorKind::NotFound)),
+            None
+        );
+    }
+
+    #[test]
+    fn map_kind() {
+        let rewrite = |_| io::Error::from(ErrorKind::PermissionDenied);
+
+        assert_eq!(
+            kind(fail(ErrorKind::NotFound).map_kind(ErrorKind::NotFound, rewrite)),
+            Some(ErrorKind::PermissionDenied)
+        );
+        assert_eq!(
+            kind(fail(ErrorKind::Other).map_kind(ErrorKind::NotFound, rewrite)),
+            Some(ErrorKind::Other)
+        );
+    }
+
+    #[test]
+    fn result_adapters() {
+        let result: Result<u32, io::Error> = Err(io::Error::from(ErrorKind::NotFound));
+        assert_eq!(kind(result.ignore_kind(ErrorKind::NotFound)), None);
+
+        let result: Result<u32, io::Error> = Ok(10);
+        assert_eq!(kind(result.fail_only_on(ErrorKind::NotFound)), None);
+    }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn delete() -> Result<u32, io::Error> {
+            fallible_try!(fail(ErrorKind::NotFound).ignore_kind(ErrorKind::NotFound));
+            fallible_try!(fail(ErrorKind::Other).ignore_kind(ErrorKind::NotFound));
+            Ok(10)
+        }
+
+        assert_eq!(delete().unwrap_err().kind(), ErrorKind::Other);
+    }
+}
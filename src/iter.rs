@@ -0,0 +1,291 @@
+//! Iterators over the error contained within a [`Fallible`].
+//!
+//! A `Fallible<E>` yields exactly one error if it is `Fail`, and none if it is `Success`,
+//! which makes it easy to gather the errors of several outcomes:
+//!
+//! ```rust
+//! # use fallible_option::Fallible::{self, Fail, Success};
+//! let outcomes = [Fail("first"), Success, Fail("third")];
+//! let errors: Vec<_> = outcomes.into_iter().flatten().collect();
+//!
+//! assert_eq!(errors, vec!["first", "third"]);
+//! ```
+
+use core::iter::FusedIterator;
+use core::option;
+
+use crate::Fallible;
+
+impl<E> Fallible<E> {
+    /// Returns an iterator over the possibly contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let fail = Fail("uh oh!");
+    /// assert_eq!(fail.iter().next(), Some(&"uh oh!"));
+    ///
+    /// let success: Fallible<&str> = Success;
+    /// assert_eq!(success.iter().next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, E> {
+        Iter {
+            inner: self.as_ref().into_iter().inner,
+        }
+    }
+
+    /// Returns a mutable iterator over the possibly contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// let mut fail = Fail("uh oh!".to_owned());
+    /// for err in fail.iter_mut() {
+    ///     err.make_ascii_uppercase();
+    /// }
+    ///
+    /// assert_eq!(fail, Fail("UH OH!".to_owned()));
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, E> {
+        IterMut {
+            inner: self.as_mut().into_iter().inner,
+        }
+    }
+}
+
+/// An iterator over a reference to the error contained within a [`Fallible`].
+///
+/// This `struct` is created by the [`Fallible::iter`] function.
+#[derive(Debug)]
+pub struct Iter<'a, E> {
+    inner: option::IntoIter<&'a E>,
+}
+
+impl<E> Clone for Iter<'_, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, E> Iterator for Iter<'a, E> {
+    type Item = &'a E;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a E> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, E> DoubleEndedIterator for Iter<'a, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a E> {
+        self.inner.next_back()
+    }
+}
+
+impl<E> ExactSizeIterator for Iter<'_, E> {}
+
+impl<E> FusedIterator for Iter<'_, E> {}
+
+/// An iterator over a mutable reference to the error contained within a [`Fallible`].
+///
+/// This `struct` is created by the [`Fallible::iter_mut`] function.
+#[derive(Debug)]
+pub struct IterMut<'a, E> {
+    inner: option::IntoIter<&'a mut E>,
+}
+
+impl<'a, E> Iterator for IterMut<'a, E> {
+    type Item = &'a mut E;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut E> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, E> DoubleEndedIterator for IterMut<'a, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut E> {
+        self.inner.next_back()
+    }
+}
+
+impl<E> ExactSizeIterator for IterMut<'_, E> {}
+
+impl<E> FusedIterator for IterMut<'_, E> {}
+
+/// An iterator over the error contained within a [`Fallible`].
+///
+/// This `struct` is created by the `into_iter` method on [`Fallible`]
+/// (provided by the [`IntoIterator`] trait).
+#[derive(Clone, Debug)]
+pub struct IntoIter<E> {
+    inner: option::IntoIter<E>,
+}
+
+impl<E> Iterator for IntoIter<E> {
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<E> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<E> DoubleEndedIterator for IntoIter<E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<E> {
+        self.inner.next_back()
+    }
+}
+
+impl<E> ExactSizeIterator for IntoIter<E> {}
+
+impl<E> FusedIterator for IntoIter<E> {}
+
+impl<E> IntoIterator for Fallible<E> {
+    type Item = E;
+    type IntoIter = IntoIter<E>;
+
+    /// Returns a consuming iterator over the possibly contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// let fail = Fail("uh oh!");
+    /// let errors: Vec<&str> = fail.into_iter().collect();
+    ///
+    /// assert_eq!(errors, vec!["uh oh!"]);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> IntoIter<E> {
+        IntoIter {
+            inner: self.into_option().into_iter(),
+        }
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Fallible<E> {
+    type Item = &'a E;
+    type IntoIter = Iter<'a, E>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, E> {
+        self.iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a mut Fallible<E> {
+    type Item = &'a mut E;
+    type IntoIter = IterMut<'a, E>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, E> {
+        self.iter_mut()
+    }
+}
+
+impl<E> FromIterator<Fallible<E>> for Fallible<E> {
+    /// Takes each outcome in the iterator, returning the first `Fail` encountered.
+    /// If no `Fail` is encountered, `Success` is returned.
+    ///
+    /// The iterator is not polled any further once a `Fail` has been produced.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// fn fails_if_zero(n: &u32) -> Fallible<&'static str> {
+    ///     if *n == 0 { Fail("number is zero") } else { Success }
+    /// }
+    ///
+    /// let outcome: Fallible<&str> = [1, 2, 3].iter().map(fails_if_zero).collect();
+    /// assert_eq!(outcome, Success);
+    ///
+    /// let outcome: Fallible<&str> = [1, 0, 3].iter().map(fails_if_zero).collect();
+    /// assert_eq!(outcome, Fail("number is zero"));
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Fallible<E>>>(iter: I) -> Self {
+        iter.into_iter().try_for_each(Fallible::result).into()
+    }
+}
+
+impl<E> FromIterator<Fallible<E>> for Result<(), E> {
+    /// Takes each outcome in the iterator, returning the error of the first `Fail`
+    /// encountered as an `Err`. If no `Fail` is encountered, `Ok(())` is returned.
+    ///
+    /// The iterator is not polled any further once a `Fail` has been produced.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let outcome: Result<(), &str> = [Success, Fail("second"), Fail("third")]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(outcome, Err("second"));
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Fallible<E>>>(iter: I) -> Self {
+        iter.into_iter().try_for_each(Fallible::result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::Fallible::{self, Fail, Success};
+
+    fn outcomes(polled: &Cell<usize>) -> impl Iterator<Item = Fallible<usize>> + '_ {
+        (0..5).map(move |n| {
+            polled.set(polled.get() + 1);
+            if n == 1 || n == 3 {
+                Fail(n)
+            } else {
+                Success
+            }
+        })
+    }
+
+    #[test]
+    fn collect_stops_at_first_fail() {
+        let polled = Cell::new(0);
+        let outcome: Fallible<usize> = outcomes(&polled).collect();
+
+        assert_eq!(outcome, Fail(1));
+        assert_eq!(polled.get(), 2);
+    }
+
+    #[test]
+    fn collect_result_stops_at_first_fail() {
+        let polled = Cell::new(0);
+        let outcome: Result<(), usize> = outcomes(&polled).collect();
+
+        assert_eq!(outcome, Err(1));
+        assert_eq!(polled.get(), 2);
+    }
+
+    #[test]
+    fn collect_all_successes() {
+        let outcome: Fallible<usize> = (0..5).map(|_| Success).collect();
+
+        assert_eq!(outcome, Success);
+    }
+}
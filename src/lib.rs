@@ -19,6 +19,7 @@
 //! ```rust
 //! use fallible_option::Fallible::{self, Fail, Success};
 //!
+//! # #[cfg(feature = "nightly")]
 //! # fn test_chained_failures() {
 //! // Validates the input number `n`, returning a `Fail`
 //! // if the input number is zero, or `Success` otherwise.
@@ -122,11 +123,15 @@
 //!     }
 //! }
 //! ```
+//!
+//! With the `derive` feature enabled, the `#[fallible]` attribute performs this rewrite
+//! for you, which allows existing code to be migrated one function at a time.
+//!
 //! ## Compatibility
 //!
 //! `Fallible` contains utility functions for mapping to and from [`Result`] and [`Option`],
-//! as well as [`FromResidual`] implementations for automatically performing these conversions
-//! when used with the `?` operator.
+//! as well as [`FromResidual`](https://doc.rust-lang.org/stable/core/ops/trait.FromResidual.html)
+//! implementations for automatically performing these conversions when used with the `?` operator.
 //! ```rust
 //! # use fallible_option::Fallible::{self, Fail, Success};
 //! fn fails_if_true(should_fail: bool) -> Fallible<&'static str> {
@@ -137,6 +142,7 @@
 //!     }
 //! }
 //!
+//! # #[cfg(feature = "nightly")]
 //! fn try_producing_value() -> Result<u32, &'static str> {
 //!     fails_if_true(false)?;
 //!     fails_if_true(true)?;
@@ -144,6 +150,31 @@
 //!     Ok(10)
 //! }
 //! ```
+//!
+//! ## Stable toolchains
+//!
+//! The `Try` and `FromResidual` traits which make the `?` operator work with `Fallible` are
+//! still unstable, and are therefore only implemented when the `nightly` feature is enabled.
+//!
+//! Without it, every combinator is still available, and the [`fallible_try!`] macro can
+//! be used in place of the `?` operator, so the same code compiles on both channels:
+//! ```rust
+//! # use fallible_option::{fallible_try, Fallible::{self, Fail, Success}};
+//! # fn fails_if_true(should_fail: bool) -> Fallible<&'static str> {
+//! #     if should_fail { Fail("Darn it!") } else { Success }
+//! # }
+//! fn try_producing_value() -> Result<u32, &'static str> {
+//!     fallible_try!(fails_if_true(false));
+//!     fallible_try!(fails_if_true(true));
+//!
+//!     Ok(10)
+//! }
+//!
+//! assert_eq!(try_producing_value(), Err("Darn it!"));
+//! ```
+//!
+//! The [`fail!`], [`ensure!`], [`fail_if!`] and [`ensure_eq!`] macros return early
+//! the same way, and can be used to replace `if cond { return Fail(e) }` checks.
 
 #![no_std]
 #![deny(
@@ -155,7 +186,6 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
     unconditional_recursion,
     unused,
     unused_allocation,
@@ -172,19 +202,97 @@
     unused_results
 )]
 #![forbid(unsafe_code)]
-#![feature(try_trait_v2)]
-#![feature(const_trait_impl)]
-#![feature(const_mut_refs)]
-#![feature(const_replace)]
-use core::convert::Infallible;
-use core::fmt::Debug;
-use core::marker::Destruct;
+#![cfg_attr(feature = "nightly", feature(try_trait_v2, try_trait_v2_residual))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+// Allows the code generated by `derive(Validate)` to refer to this crate in tests.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as fallible_option;
+
+use core::fmt::{self, Debug, Display};
 use core::mem;
-use core::ops::{ControlFlow, Deref, DerefMut, FromResidual, Try};
+use core::ops::{ControlFlow, Deref, DerefMut};
+#[cfg(feature = "nightly")]
+use core::{
+    convert::Infallible,
+    ops::{FromResidual, Residual, Try},
+};
+
+#[cfg(feature = "anyhow")]
+pub mod anyhow;
+mod context;
+#[cfg(feature = "std")]
+mod error;
+#[cfg(feature = "eyre")]
+pub mod eyre;
+#[cfg(feature = "alloc")]
+mod failures;
+#[cfg(feature = "std")]
+pub mod fs;
+#[cfg(feature = "async")]
+pub mod future;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
+mod located;
+mod macros;
+mod option;
+#[cfg(feature = "std")]
+mod panic;
+#[cfg(feature = "std")]
+pub mod process;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "futures-core")]
+pub mod stream;
+#[cfg(feature = "std")]
+mod termination;
+#[cfg(feature = "std")]
+pub mod thread;
+#[cfg(feature = "backtrace")]
+mod traced;
+#[cfg(feature = "derive")]
+mod validate;
+
+pub use context::{Context, Contextual};
+#[cfg(feature = "alloc")]
+pub use failures::Failures;
+#[cfg(feature = "derive")]
+pub use fallible_option_derive::{fallible, Validate};
+pub use located::Located;
+pub use option::{OptionExt, OptionFallibleExt};
+#[cfg(feature = "std")]
+pub use panic::{JoinHandleExt, Panicked};
+#[cfg(feature = "backtrace")]
+pub use traced::Traced;
+#[cfg(feature = "derive")]
+pub use validate::ValidationError;
+
+/// Support for the code generated by `fallible-option-derive`, which is not public API.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    /// Names the error type of a `Result<(), E>`, such that `#[fallible]` can be applied
+    /// to functions returning an alias such as `io::Result<()>`.
+    #[diagnostic::on_unimplemented(
+        message = "`#[fallible]` can only be applied to functions returning `Result<(), E>`",
+        label = "this is not a `Result<(), E>`"
+    )]
+    pub trait UnitResult {
+        type Error;
+    }
+
+    impl<E> UnitResult for Result<(), E> {
+        type Error = E;
+    }
+}
 
 /// Outcome of an operation that does not produce a value on success.
 #[must_use]
 #[derive(Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Fallible<E> {
     /// No error was produced.
     Success,
@@ -194,6 +302,30 @@ pub enum Fallible<E> {
 
 use Fallible::{Fail, Success};
 
+/// Residual of a `Fallible<E>`, produced when the `?` operator is applied to a `Fail(e)`.
+///
+/// Unlike `Fallible<E>` itself, `Failed<E>` can only ever represent a failure, which allows
+/// types outside of this crate to accept `Fallible` residuals by implementing
+/// `FromResidual<Failed<E>>`, without having to account for a `Success` value.
+///
+/// ```rust
+/// # use fallible_option::{Failed, Fallible::{self, Fail}};
+/// let failed = Failed("something went wrong");
+///
+/// assert_eq!(Fallible::from(failed), Fail("something went wrong"));
+/// assert_eq!(failed.into_inner(), "something went wrong");
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+pub struct Failed<E>(pub E);
+
+impl<E> Failed<E> {
+    /// Unwraps the error contained within the residual.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
 impl<E> Fallible<E> {
     /// Converts from `Fallible<E>` (or `&Fallible<E>`) to `Fallible<&E::Target>`.
     ///
@@ -206,9 +338,9 @@ impl<E> Fallible<E> {
     /// assert_eq!(fail.as_deref(), Fail("something went wrong"))
     /// ```
     #[inline]
-    pub const fn as_deref(&self) -> Fallible<&<E as Deref>::Target>
+    pub fn as_deref(&self) -> Fallible<&<E as Deref>::Target>
     where
-        E: ~const Deref,
+        E: Deref,
     {
         match self {
             Success => Success,
@@ -234,9 +366,9 @@ impl<E> Fallible<E> {
     /// ```
     ///
     #[inline]
-    pub const fn as_deref_mut(&mut self) -> Fallible<&mut <E as Deref>::Target>
+    pub fn as_deref_mut(&mut self) -> Fallible<&mut <E as Deref>::Target>
     where
-        E: ~const DerefMut,
+        E: DerefMut,
     {
         match self {
             Success => Success,
@@ -320,6 +452,7 @@ impl<E> Fallible<E> {
     /// assert_eq!(fail.unwrap_fail(), 70);
     /// ```
     #[inline]
+    #[track_caller]
     pub fn unwrap_fail(self) -> E {
         match self {
             Success => panic!("called `Fallible::unwrap_fail()` on a `Fallible::Success` value"),
@@ -327,6 +460,28 @@ impl<E> Fallible<E> {
         }
     }
 
+    /// Unwrap the contained error or panics with the message `msg` if no error has occurred.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let fail = Fail(70);
+    /// assert_eq!(fail.expect_fail("expected a failure"), 70);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let fail: Fallible<u32> = Success;
+    /// fail.expect_fail("expected a failure");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect_fail(self, msg: &str) -> E {
+        match self {
+            Success => panic!("{msg}"),
+            Fail(err) => err,
+        }
+    }
+
     /// Returns `true` if the Fallible is a `Fail` value containing an error
     /// equivalent to `f`
     ///
@@ -336,7 +491,7 @@ impl<E> Fallible<E> {
     /// assert!(fail.contains(&"hello"))
     /// ```
     #[inline]
-    pub const fn contains<U: ~const PartialEq<E>>(&self, x: &U) -> bool {
+    pub fn contains<U: PartialEq<E>>(&self, x: &U) -> bool {
         match self {
             Success => false,
             Fail(e) => x.eq(e),
@@ -354,11 +509,9 @@ impl<E> Fallible<E> {
     /// assert_eq!(fail, Fail("hello world!".to_owned()));
     /// ```
     #[inline]
-    pub const fn map<F, U>(self, op: F) -> Fallible<U>
+    pub fn map<F, U>(self, op: F) -> Fallible<U>
     where
-        F: ~const FnOnce(E) -> U,
-        F: ~const Destruct,
-        E: ~const Destruct,
+        F: FnOnce(E) -> U,
     {
         match self {
             Success => Success,
@@ -375,10 +528,7 @@ impl<E> Fallible<E> {
     /// assert_eq!(fail, Err("error"));
     /// ```
     #[inline]
-    pub const fn result(self) -> Result<(), E>
-    where
-        E: ~const Destruct,
-    {
+    pub fn result(self) -> Result<(), E> {
         match self {
             Success => Ok(()),
             Fail(e) => Err(e),
@@ -402,6 +552,23 @@ impl<E> Fallible<E> {
         }
     }
 
+    /// Converts the `Fallible<E>` into an `Option<E>`, yielding none
+    /// if no error occurred.
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// let fail = Fail("error occurred");
+    /// let maybe_error = fail.into_option();
+    ///
+    /// assert_eq!(maybe_error, Some("error occurred"));
+    /// ```
+    #[inline]
+    pub fn into_option(self) -> Option<E> {
+        match self {
+            Success => None,
+            Fail(err) => Some(err),
+        }
+    }
+
     /// Constructs a `Result<T, E>` from self.
     /// ```rust
     /// # use fallible_option::Fallible::{self, Fail};
@@ -411,17 +578,31 @@ impl<E> Fallible<E> {
     /// ```
     /// `Fail(e)` becomes `Err(e)` and `Success` becomes `Ok(value)`
     #[inline]
-    pub const fn err_or<T>(self, value: T) -> Result<T, E>
-    where
-        E: ~const Destruct,
-        T: ~const Destruct,
-    {
+    pub fn err_or<T>(self, value: T) -> Result<T, E> {
         match self {
             Success => Ok(value),
             Fail(e) => Err(e),
         }
     }
 
+    /// Transforms the `Fallible<E>` into a `ControlFlow<E, ()>`, where `Fail(e)`
+    /// becomes `Break(e)` and `Success` becomes `Continue(())`
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// use std::ops::ControlFlow;
+    ///
+    /// let flow = Fail("found it").into_control_flow();
+    ///
+    /// assert_eq!(flow, ControlFlow::Break("found it"));
+    /// ```
+    #[inline]
+    pub fn into_control_flow(self) -> ControlFlow<E, ()> {
+        match self {
+            Success => ControlFlow::Continue(()),
+            Fail(e) => ControlFlow::Break(e),
+        }
+    }
+
     /// Replaces the contained error (if any) with None,
     /// and returns an `Option<E>` with the contained error,
     /// if the outcome was `Fail`.
@@ -435,26 +616,371 @@ impl<E> Fallible<E> {
     /// assert_eq!(err, Some("something went wrong"));
     /// ```
     #[inline]
-    pub const fn take(&mut self) -> Option<E>
-    where
-        E: ~const Destruct,
-    {
+    pub fn take(&mut self) -> Option<E> {
         match mem::replace(self, Success) {
             Success => None,
             Fail(e) => Some(e),
         }
     }
+
+    /// Returns `true` if the value is `Fail` and `f` returns `true` when called
+    /// with the contained error, otherwise returns `false`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail(404).is_fail_and(|code| code >= 400), true);
+    /// assert_eq!(Fail(200).is_fail_and(|code| code >= 400), false);
+    /// assert_eq!(Success::<u32>.is_fail_and(|code| code >= 400), false);
+    /// ```
+    #[inline]
+    pub fn is_fail_and<F>(self, f: F) -> bool
+    where
+        F: FnOnce(E) -> bool,
+    {
+        match self {
+            Success => false,
+            Fail(e) => f(e),
+        }
+    }
+
+    /// Returns `true` if the value is `Success`, or if `f` returns `true` when called
+    /// with the contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Success::<u32>.is_success_or(|code| code < 400), true);
+    /// assert_eq!(Fail(200).is_success_or(|code| code < 400), true);
+    /// assert_eq!(Fail(404).is_success_or(|code| code < 400), false);
+    /// ```
+    #[inline]
+    pub fn is_success_or<F>(self, f: F) -> bool
+    where
+        F: FnOnce(E) -> bool,
+    {
+        match self {
+            Success => true,
+            Fail(e) => f(e),
+        }
+    }
+
+    /// Returns `other` if the value is `Success`, otherwise returns the `Fail` of self.
+    ///
+    /// Arguments passed to `and` are eagerly evaluated; if you are passing the
+    /// result of a function call, it is recommended to use [`and_then`](Fallible::and_then),
+    /// which is lazily evaluated.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Success.and(Fail("second")), Fail("second"));
+    /// assert_eq!(Fail("first").and(Fail("second")), Fail("first"));
+    /// assert_eq!(Fail("first").and(Success), Fail("first"));
+    /// assert_eq!(Success::<&str>.and(Success), Success);
+    /// ```
+    #[inline]
+    pub fn and(self, other: Fallible<E>) -> Fallible<E> {
+        match self {
+            Success => other,
+            Fail(e) => Fail(e),
+        }
+    }
+
+    /// Calls `op` if the value is `Success`, otherwise returns the `Fail` of self.
+    ///
+    /// This can be used to chain operations which should only be attempted if all
+    /// previous operations succeeded.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// fn fails_if_zero(n: u32) -> Fallible<&'static str> {
+    ///     if n == 0 { Fail("number is zero") } else { Success }
+    /// }
+    ///
+    /// assert_eq!(fails_if_zero(1).and_then(|| fails_if_zero(2)), Success);
+    /// assert_eq!(fails_if_zero(1).and_then(|| fails_if_zero(0)), Fail("number is zero"));
+    /// ```
+    #[inline]
+    pub fn and_then<F>(self, op: F) -> Fallible<E>
+    where
+        F: FnOnce() -> Fallible<E>,
+    {
+        match self {
+            Success => op(),
+            Fail(e) => Fail(e),
+        }
+    }
+
+    /// Returns `Success` if the value is `Success`, otherwise returns `other`.
+    ///
+    /// Arguments passed to `or` are eagerly evaluated; if you are passing the
+    /// result of a function call, it is recommended to use [`or_else`](Fallible::or_else),
+    /// which is lazily evaluated.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail("first").or(Fail("second")), Fail("second"));
+    /// assert_eq!(Fail("first").or(Success::<&str>), Success);
+    /// assert_eq!(Success::<&str>.or(Fail("second")), Success);
+    /// ```
+    #[inline]
+    pub fn or<U>(self, other: Fallible<U>) -> Fallible<U> {
+        match self {
+            Success => Success,
+            Fail(_) => other,
+        }
+    }
+
+    /// Returns `Success` if the value is `Success`, otherwise calls `op` with the
+    /// contained error and returns the outcome.
+    ///
+    /// This can be used to attempt recovery from a failure.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let recover = |err: &'static str| if err == "retry" { Success } else { Fail(err.len()) };
+    ///
+    /// assert_eq!(Fail("retry").or_else(recover), Success);
+    /// assert_eq!(Fail("fatal").or_else(recover), Fail(5));
+    /// assert_eq!(Success.or_else(recover), Success);
+    /// ```
+    #[inline]
+    pub fn or_else<U, F>(self, op: F) -> Fallible<U>
+    where
+        F: FnOnce(E) -> Fallible<U>,
+    {
+        match self {
+            Success => Success,
+            Fail(e) => op(e),
+        }
+    }
+
+    /// Returns the `Fail` of either `self` or `other` if exactly one of them is `Fail`,
+    /// otherwise returns `Success`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail("first").xor(Success), Fail("first"));
+    /// assert_eq!(Success.xor(Fail("second")), Fail("second"));
+    /// assert_eq!(Fail("first").xor(Fail("second")), Success);
+    /// assert_eq!(Success::<&str>.xor(Success), Success);
+    /// ```
+    #[inline]
+    pub fn xor(self, other: Fallible<E>) -> Fallible<E> {
+        match (self, other) {
+            (Fail(e), Success) | (Success, Fail(e)) => Fail(e),
+            _ => Success,
+        }
+    }
+
+    /// Returns `Success` if the value is `Success`, or if `predicate` returns `false` when
+    /// called with the contained error. Otherwise returns the `Fail` of self.
+    ///
+    /// This can be used to discard errors which should not be considered failures.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let is_fatal = |code: &u32| *code >= 500;
+    ///
+    /// assert_eq!(Fail(503).filter(is_fatal), Fail(503));
+    /// assert_eq!(Fail(404).filter(is_fatal), Success);
+    /// assert_eq!(Success.filter(is_fatal), Success);
+    /// ```
+    #[inline]
+    pub fn filter<P>(self, predicate: P) -> Fallible<E>
+    where
+        P: FnOnce(&E) -> bool,
+    {
+        match self {
+            Fail(e) if predicate(&e) => Fail(e),
+            _ => Success,
+        }
+    }
+
+    /// Calls `f` with a reference to the contained error, if the value is `Fail`.
+    ///
+    /// Returns the original `Fallible`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// let mut seen = None;
+    /// let fail = Fail("something went wrong").inspect(|err| seen = Some(*err));
+    ///
+    /// assert_eq!(fail, Fail("something went wrong"));
+    /// assert_eq!(seen, Some("something went wrong"));
+    /// ```
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Fallible<E>
+    where
+        F: FnOnce(&E),
+    {
+        if let Fail(ref e) = self {
+            f(e);
+        }
+
+        self
+    }
+
+    /// Returns `default` if the value is `Success`, otherwise applies `f` to the
+    /// contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail("uh oh!").map_or(0, |err| err.len()), 6);
+    /// assert_eq!(Success::<&str>.map_or(0, |err| err.len()), 0);
+    /// ```
+    #[inline]
+    pub fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        F: FnOnce(E) -> U,
+    {
+        match self {
+            Success => default,
+            Fail(e) => f(e),
+        }
+    }
+
+    /// Computes a value from `default` if the value is `Success`, otherwise applies `f`
+    /// to the contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail("uh oh!").map_or_else(|| 0, |err| err.len()), 6);
+    /// assert_eq!(Success::<&str>.map_or_else(|| 0, |err| err.len()), 0);
+    /// ```
+    #[inline]
+    pub fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(E) -> U,
+    {
+        match self {
+            Success => default(),
+            Fail(e) => f(e),
+        }
+    }
+
+    /// Returns the contained error, or `default` if the value is `Success`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail("uh oh!").unwrap_fail_or("no error"), "uh oh!");
+    /// assert_eq!(Success.unwrap_fail_or("no error"), "no error");
+    /// ```
+    #[inline]
+    pub fn unwrap_fail_or(self, default: E) -> E {
+        match self {
+            Success => default,
+            Fail(e) => e,
+        }
+    }
+
+    /// Returns the contained error, or computes one from `f` if the value is `Success`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail("uh oh!").unwrap_fail_or_else(|| "no error"), "uh oh!");
+    /// assert_eq!(Success.unwrap_fail_or_else(|| "no error"), "no error");
+    /// ```
+    #[inline]
+    pub fn unwrap_fail_or_else<F>(self, f: F) -> E
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Success => f(),
+            Fail(e) => e,
+        }
+    }
+
+    /// Returns the contained error, or the [`Default`] value of `E` if the value is `Success`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail(404).unwrap_fail_or_default(), 404);
+    /// assert_eq!(Success::<u32>.unwrap_fail_or_default(), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_fail_or_default(self) -> E
+    where
+        E: Default,
+    {
+        match self {
+            Success => E::default(),
+            Fail(e) => e,
+        }
+    }
+
+    /// Replaces the value with `Fail(err)`, returning the previous value.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let mut fail = Success;
+    ///
+    /// assert_eq!(fail.replace("first"), Success);
+    /// assert_eq!(fail.replace("second"), Fail("first"));
+    /// assert_eq!(fail, Fail("second"));
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, err: E) -> Fallible<E> {
+        mem::replace(self, Fail(err))
+    }
+
+    /// Replaces the value with `Fail(err)`, returning a mutable reference to the
+    /// newly inserted error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let mut fail = Success;
+    /// let err = fail.insert("uh oh!".to_owned());
+    /// err.make_ascii_uppercase();
+    ///
+    /// assert_eq!(fail, Fail("UH OH!".to_owned()));
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, err: E) -> &mut E {
+        *self = Fail(err);
+
+        match self {
+            Fail(e) => e,
+            Success => unreachable!(),
+        }
+    }
+
+    /// Inserts the error computed by `f` if the value is `Success`, then returns
+    /// a mutable reference to the contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let mut fail = Success;
+    ///
+    /// assert_eq!(fail.get_or_insert_with(|| "first"), &mut "first");
+    /// assert_eq!(fail.get_or_insert_with(|| "second"), &mut "first");
+    /// assert_eq!(fail, Fail("first"));
+    /// ```
+    #[inline]
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut E
+    where
+        F: FnOnce() -> E,
+    {
+        if let Success = self {
+            *self = Fail(f());
+        }
+
+        match self {
+            Fail(e) => e,
+            Success => unreachable!(),
+        }
+    }
 }
 
 impl<E> Fallible<&E>
 where
-    E: ~const Clone,
+    E: Clone,
 {
     /// Maps a `Fallible<&E>` to a [`Fallible<E>`] by cloning the contents of the
     /// error.
     #[inline]
     #[must_use = "`self` will be dropped if the result is not used"]
-    pub const fn cloned(self) -> Fallible<E> {
+    pub fn cloned(self) -> Fallible<E> {
         match self {
             Success => Success,
             Fail(e) => Fail(e.clone()),
@@ -478,13 +1004,13 @@ where
 
 impl<E> Fallible<&mut E>
 where
-    E: ~const Clone,
+    E: Clone,
 {
     /// Maps an `Fallible<&E>` to an `Fallible<E>` by cloning the contents of the
     /// error.
     #[inline]
     #[must_use = "`self` will be dropped if the result is not used"]
-    pub const fn cloned(self) -> Fallible<E> {
+    pub fn cloned(self) -> Fallible<E> {
         match self {
             Success => Success,
             Fail(e) => Fail(e.clone()),
@@ -528,6 +1054,7 @@ where
     /// fail.unwrap();
     /// ```
     #[inline]
+    #[track_caller]
     pub fn unwrap(self) {
         match self {
             Success => (),
@@ -536,15 +1063,37 @@ where
             }
         }
     }
+
+    /// Returns a unit value if the `Fallible` is not `Fail`.
+    ///
+    /// # Panics
+    /// Panics if the value is a `Fail`, with a panic message including
+    /// the passed message, and the content of the `Fail`.
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let success: Fallible<()> = Success;
+    /// success.expect("should have succeeded");
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// let fail = Fail("hello world");
+    /// fail.expect("should have succeeded");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn expect(self, msg: &str) {
+        match self {
+            Success => (),
+            Fail(e) => panic!("{msg}: {e:?}"),
+        }
+    }
 }
 
 impl<E> Fallible<Fallible<E>> {
     /// Flattens a `Fallible<Fallible<E>>` into a `Fallible<E>`
     #[inline]
-    pub const fn flatten(self) -> Fallible<E>
-    where
-        E: ~const Destruct,
-    {
+    pub fn flatten(self) -> Fallible<E> {
         match self {
             Success => Success,
             Fail(inner) => inner,
@@ -552,18 +1101,36 @@ impl<E> Fallible<Fallible<E>> {
     }
 }
 
-impl<E> const From<E> for Fallible<E> {
+impl<E> Fallible<Option<E>> {
+    /// Transposes a `Fallible<Option<E>>` into an `Option<Fallible<E>>`.
+    ///
+    /// `Success` becomes `Some(Success)`, `Fail(Some(e))` becomes `Some(Fail(e))`,
+    /// and `Fail(None)` becomes `None`.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Fail(Some("uh oh!")).transpose(), Some(Fail("uh oh!")));
+    /// assert_eq!(Fail(None::<&str>).transpose(), None);
+    /// assert_eq!(Success::<Option<&str>>.transpose(), Some(Success));
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Option<Fallible<E>> {
+        match self {
+            Success => Some(Success),
+            Fail(Some(e)) => Some(Fail(e)),
+            Fail(None) => None,
+        }
+    }
+}
+
+impl<E> From<E> for Fallible<E> {
     #[inline]
     fn from(value: E) -> Self {
         Fail(value)
     }
 }
 
-impl<T, E> const From<Result<T, E>> for Fallible<E>
-where
-    E: ~const Destruct,
-    T: ~const Destruct,
-{
+impl<T, E> From<Result<T, E>> for Fallible<E> {
     #[inline]
     fn from(value: Result<T, E>) -> Self {
         match value {
@@ -573,30 +1140,72 @@ where
     }
 }
 
-impl<'a, E> const From<&'a Fallible<E>> for Fallible<&'a E> {
+impl<E> From<Fallible<E>> for Result<(), E> {
+    #[inline]
+    fn from(value: Fallible<E>) -> Self {
+        value.result()
+    }
+}
+
+impl<E> From<Option<E>> for Fallible<E> {
+    /// Converts a legacy `Option<E>`, where `Some(e)` signals an error, into a `Fallible<E>`.
+    #[inline]
+    fn from(value: Option<E>) -> Self {
+        match value {
+            None => Success,
+            Some(e) => Fail(e),
+        }
+    }
+}
+
+impl<E> From<Fallible<E>> for Option<E> {
+    #[inline]
+    fn from(value: Fallible<E>) -> Self {
+        value.into_option()
+    }
+}
+
+impl<E> From<ControlFlow<E, ()>> for Fallible<E> {
+    #[inline]
+    fn from(value: ControlFlow<E, ()>) -> Self {
+        match value {
+            ControlFlow::Continue(()) => Success,
+            ControlFlow::Break(e) => Fail(e),
+        }
+    }
+}
+
+impl<E> From<Failed<E>> for Fallible<E> {
+    #[inline]
+    fn from(value: Failed<E>) -> Self {
+        Fail(value.0)
+    }
+}
+
+impl<'a, E> From<&'a Fallible<E>> for Fallible<&'a E> {
     #[inline]
     fn from(value: &'a Fallible<E>) -> Self {
         value.as_ref()
     }
 }
 
-impl<'a, E> const From<&'a mut Fallible<E>> for Fallible<&'a mut E> {
+impl<'a, E> From<&'a mut Fallible<E>> for Fallible<&'a mut E> {
     #[inline]
     fn from(value: &'a mut Fallible<E>) -> Self {
         value.as_mut()
     }
 }
 
-impl<E> const Default for Fallible<E> {
+impl<E> Default for Fallible<E> {
     #[inline]
     fn default() -> Self {
         Success
     }
 }
 
-impl<E> const Clone for Fallible<E>
+impl<E> Clone for Fallible<E>
 where
-    E: ~const Clone + ~const Destruct,
+    E: Clone,
 {
     #[inline]
     fn clone(&self) -> Self {
@@ -615,9 +1224,31 @@ where
     }
 }
 
+impl<E> Display for Fallible<E>
+where
+    E: Display,
+{
+    /// Formats `Success` as the literal string "success", and `Fail(e)`
+    /// using the [`Display`] implementation of the contained error.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// assert_eq!(Success::<&str>.to_string(), "success");
+    /// assert_eq!(Fail("something went wrong").to_string(), "something went wrong");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Success => f.write_str("success"),
+            Fail(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
 impl<E> Try for Fallible<E> {
     type Output = ();
-    type Residual = Fallible<E>;
+    type Residual = Failed<E>;
 
     #[inline]
     fn from_output(_: Self::Output) -> Self {
@@ -628,37 +1259,64 @@ impl<E> Try for Fallible<E> {
     fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
         match self {
             Success => ControlFlow::Continue(()),
-            Fail(e) => ControlFlow::Break(Fail(e)),
+            Fail(e) => ControlFlow::Break(Failed(e)),
         }
     }
 }
 
-impl<E, U> FromResidual<Fallible<U>> for Fallible<E>
+#[cfg(feature = "nightly")]
+impl<E> Residual<()> for Failed<E> {
+    type TryType = Fallible<E>;
+}
+
+#[cfg(feature = "nightly")]
+impl<E, U> FromResidual<Failed<U>> for Fallible<E>
 where
     E: From<U>,
 {
     #[inline]
-    fn from_residual(residual: Fallible<U>) -> Self {
-        match residual {
-            Success => Success,
-            Fail(u) => Fail(u.into()),
-        }
+    fn from_residual(residual: Failed<U>) -> Self {
+        Fail(residual.0.into())
     }
 }
 
-impl<T, E, U> FromResidual<Fallible<U>> for Result<T, E>
+#[cfg(feature = "nightly")]
+impl<T, E, U> FromResidual<Failed<U>> for Result<T, E>
 where
     E: From<U>,
 {
     #[inline]
-    fn from_residual(residual: Fallible<U>) -> Self {
+    fn from_residual(residual: Failed<U>) -> Self {
+        Err(residual.0.into())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<B, C, U> FromResidual<Failed<U>> for ControlFlow<B, C>
+where
+    B: From<U>,
+{
+    #[inline]
+    fn from_residual(residual: Failed<U>) -> Self {
+        ControlFlow::Break(residual.0.into())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<E, B> FromResidual<ControlFlow<B, Infallible>> for Fallible<E>
+where
+    E: From<B>,
+{
+    #[inline]
+    fn from_residual(residual: ControlFlow<B, Infallible>) -> Self {
         match residual {
-            Success => unreachable!(),
-            Fail(e) => Err(e.into()),
+            ControlFlow::Continue(_) => Success,
+            ControlFlow::Break(b) => Fail(b.into()),
         }
     }
 }
 
+#[cfg(feature = "nightly")]
 impl<E, U> FromResidual<Result<(), U>> for Fallible<E>
 where
     E: From<U>,
@@ -672,6 +1330,7 @@ where
     }
 }
 
+#[cfg(feature = "nightly")]
 impl<E, U> FromResidual<Result<Infallible, U>> for Fallible<E>
 where
     E: From<U>,
@@ -685,6 +1344,99 @@ where
     }
 }
 
+/// Stable counterpart to [`FromResidual`](https://doc.rust-lang.org/stable/core/ops/trait.FromResidual.html),
+/// used by the [`fallible_try!`] macro to construct the early-exit value of the enclosing
+/// function from the error contained in a `Fail`.
+pub trait FromFail<U> {
+    /// Constructs the type from the error `U` of a `Fail`.
+    fn from_fail(err: U) -> Self;
+}
+
+impl<E, U> FromFail<U> for Fallible<E>
+where
+    E: From<U>,
+{
+    #[inline]
+    fn from_fail(err: U) -> Self {
+        Fail(err.into())
+    }
+}
+
+impl<T, E, U> FromFail<U> for Result<T, E>
+where
+    E: From<U>,
+{
+    #[inline]
+    fn from_fail(err: U) -> Self {
+        Err(err.into())
+    }
+}
+
+impl<B, C, U> FromFail<U> for ControlFlow<B, C>
+where
+    B: From<U>,
+{
+    #[inline]
+    fn from_fail(err: U) -> Self {
+        ControlFlow::Break(err.into())
+    }
+}
+
+/// Stable substitute for the `?` operator on a `Fallible<E>`.
+///
+/// Returns early from the enclosing function if the expression evaluates to `Fail(e)`,
+/// converting `e` using [`FromFail`], or evaluates to `()` if the expression is `Success`.
+///
+/// Works in functions returning either `Fallible<E>` or `Result<T, E>`, as long as
+/// the error type can be constructed `From` the contained error.
+///
+/// ```rust
+/// # use fallible_option::{fallible_try, Fallible::{self, Fail, Success}};
+/// fn fails_if_number_is_zero(n: u32) -> Fallible<&'static str> {
+///     if n == 0 {
+///         Fail("number is zero")
+///     } else {
+///         Success
+///     }
+/// }
+///
+/// fn check_many_numbers() -> Fallible<&'static str> {
+///     fallible_try!(fails_if_number_is_zero(1));
+///     fallible_try!(fails_if_number_is_zero(0));
+///
+///     Success
+/// }
+///
+/// assert_eq!(check_many_numbers(), Fail("number is zero"));
+/// ```
+///
+/// If the enclosing function returns a `Result`, [`Fallible::result`] can be used
+/// together with the `?` operator instead:
+///
+/// ```rust
+/// # use fallible_option::Fallible::{self, Fail, Success};
+/// # fn fails_if_number_is_zero(n: u32) -> Fallible<&'static str> {
+/// #     if n == 0 { Fail("number is zero") } else { Success }
+/// # }
+/// fn check_many_numbers() -> Result<u32, &'static str> {
+///     fails_if_number_is_zero(1).result()?;
+///     fails_if_number_is_zero(0).result()?;
+///
+///     Ok(10)
+/// }
+///
+/// assert_eq!(check_many_numbers(), Err("number is zero"));
+/// ```
+#[macro_export]
+macro_rules! fallible_try {
+    ($expr:expr $(,)?) => {
+        match $expr {
+            $crate::Fallible::Success => (),
+            $crate::Fallible::Fail(err) => return $crate::FromFail::from_fail(err),
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use core::fmt::Debug;
@@ -710,6 +1462,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn fallible_residual_conversion() {
         fn outer_error() -> Fallible<OuterError> {
             always_produces_error()?;
@@ -723,6 +1476,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn result_residual_conversion() {
         fn outer_error() -> Result<(), OuterError> {
             always_produces_error()?;
@@ -733,6 +1487,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn discard_result_value() {
         fn ok() -> Result<u32, u32> {
             Ok(10)
@@ -750,4 +1505,114 @@ mod tests {
 
         assert_eq!(outer_error(), Fail(15));
     }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn outer_error() -> Fallible<OuterError> {
+            fallible_try!(Fallible::<InnerError>::Success);
+            fallible_try!(always_produces_error());
+            Success
+        }
+
+        assert_eq!(
+            outer_error().unwrap_fail(),
+            OuterError::Inner(InnerError(1))
+        );
+    }
+
+    #[test]
+    fn fallible_try_result_conversion() {
+        fn outer_error() -> Result<u32, OuterError> {
+            fallible_try!(always_produces_error());
+            Ok(10)
+        }
+
+        assert_eq!(outer_error(), Err(OuterError::Inner(InnerError(1))));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn foreign_failed_residual() {
+        use core::ops::FromResidual;
+
+        use crate::Failed;
+
+        #[derive(Debug, PartialEq)]
+        enum Outcome {
+            Done,
+            Aborted(OuterError),
+        }
+
+        impl FromResidual<Failed<InnerError>> for Outcome {
+            fn from_residual(residual: Failed<InnerError>) -> Self {
+                Outcome::Aborted(residual.into_inner().into())
+            }
+        }
+
+        fn outer_error() -> Outcome {
+            always_produces_error()?;
+            Outcome::Done
+        }
+
+        assert_eq!(
+            outer_error(),
+            Outcome::Aborted(OuterError::Inner(InnerError(1)))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn control_flow_residual_conversion() {
+        use core::ops::ControlFlow;
+
+        fn outer_error() -> ControlFlow<OuterError, u32> {
+            always_produces_error()?;
+            ControlFlow::Continue(10)
+        }
+
+        fn break_early() -> Fallible<OuterError> {
+            ControlFlow::Break(InnerError(2))?;
+            Success
+        }
+
+        assert_eq!(
+            outer_error(),
+            ControlFlow::Break(OuterError::Inner(InnerError(1)))
+        );
+        assert_eq!(break_early(), Fail(OuterError::Inner(InnerError(2))));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn try_for_each_visitor() {
+        fn visit(node: &u8) -> Fallible<InnerError> {
+            if *node > 2 {
+                Fail(InnerError(*node))
+            } else {
+                Success
+            }
+        }
+
+        assert_eq!([1, 2, 3, 4].iter().try_for_each(visit), Fail(InnerError(3)));
+        assert_eq!([1, 2].iter().try_for_each(visit), Success);
+    }
+
+    #[test]
+    fn control_flow_fallible_try_conversion() {
+        use core::ops::ControlFlow;
+
+        fn outer_error() -> ControlFlow<OuterError, u32> {
+            fallible_try!(always_produces_error());
+            ControlFlow::Continue(10)
+        }
+
+        assert_eq!(
+            outer_error(),
+            ControlFlow::Break(OuterError::Inner(InnerError(1)))
+        );
+        assert_eq!(
+            Fallible::from(ControlFlow::Break(InnerError(1))),
+            Fail(InnerError(1))
+        );
+    }
 }
@@ -0,0 +1,161 @@
+use core::fmt::{self, Display};
+use core::panic::Location;
+
+use crate::Fallible::{self, Fail, Success};
+
+/// An error `E` annotated with the source location at which the failure was produced.
+///
+/// The location is captured using `#[track_caller]`, and so requires no allocation.
+///
+/// ```rust
+/// # use fallible_option::{Located, Fallible::{self, Fail, Success}};
+/// fn fails_if_zero(n: u32) -> Fallible<Located<&'static str>> {
+///     if n == 0 {
+///         Fallible::fail_here("number is zero")
+///     } else {
+///         Success
+///     }
+/// }
+///
+/// let err = fails_if_zero(0).unwrap_fail();
+///
+/// assert_eq!(err.error(), &"number is zero");
+/// assert_eq!(err.location().file(), file!());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Located<E> {
+    error: E,
+    location: &'static Location<'static>,
+}
+
+impl<E> Located<E> {
+    /// Annotates `error` with the location of the caller.
+    #[inline]
+    #[track_caller]
+    pub fn new(error: E) -> Self {
+        Located {
+            error,
+            location: Location::caller(),
+        }
+    }
+
+    /// Borrows the annotated error.
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the location at which the failure was produced.
+    #[inline]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Discards the location, returning the annotated error.
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for Located<E>
+where
+    E: Display,
+{
+    /// Formats the annotated error, followed by the location of the failure.
+    ///
+    /// ```rust
+    /// # use fallible_option::Located;
+    /// let err = Located::new("number is zero");
+    /// let location = err.location();
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     format!("number is zero, at {}:{}:{}", location.file(), location.line(), location.column())
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, at {}", self.error, self.location)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for Located<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> Fallible<Located<E>> {
+    /// Constructs a `Fail` containing `err`, annotated with the location of the caller.
+    #[inline]
+    #[track_caller]
+    pub fn fail_here(err: E) -> Self {
+        Fail(Located::new(err))
+    }
+}
+
+impl<E> Fallible<E> {
+    /// Annotates the contained error (if any) with the location of the caller.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// let line = line!() + 1;
+    /// let err = Fail("uh oh!").located().unwrap_fail();
+    ///
+    /// assert_eq!(err.location().line(), line);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn located(self) -> Fallible<Located<E>> {
+        match self {
+            Success => Success,
+            Fail(e) => Fail(Located::new(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Fallible::{self, Success},
+        Located,
+    };
+
+    fn fails_here() -> (Fallible<Located<u8>>, u32) {
+        (Fallible::fail_here(1), line!())
+    }
+
+    #[test]
+    fn captures_caller_location() {
+        let (outcome, line) = fails_here();
+        let err = outcome.unwrap_fail();
+
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), line);
+        assert_eq!(err.into_error(), 1);
+    }
+
+    #[test]
+    fn located_success() {
+        assert_eq!(Success::<u8>.located(), Success);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unwrap_includes_location() {
+        use std::format;
+        use std::panic;
+        use std::string::String;
+
+        let (outcome, line) = fails_here();
+        let payload = panic::catch_unwind(|| outcome.unwrap()).unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+
+        assert!(message.contains(&format!("line: {line}")));
+        assert!(message.contains(file!()));
+    }
+}
@@ -0,0 +1,187 @@
+/// Returns early with the given error, converting it into the return type of the
+/// enclosing function.
+///
+/// Like [`fallible_try!`], this works in any function returning a `Fallible<E>`,
+/// `Result<T, E>` or `ControlFlow<E, C>`, as long as `E` implements `From` for the
+/// type of the error.
+///
+/// ```rust
+/// # use fallible_option::{fail, Fallible::{self, Fail, Success}};
+/// fn validate_number(x: u32) -> Fallible<&'static str> {
+///     match x {
+///         0 ..= 9 => fail!("number is too small"),
+///         10..=30 => Success,
+///         31..    => fail!("number is too large"),
+///     }
+/// }
+///
+/// assert_eq!(validate_number(40), Fail("number is too large"));
+/// ```
+#[macro_export]
+macro_rules! fail {
+    ($err:expr $(,)?) => {
+        return $crate::FromFail::from_fail($err)
+    };
+}
+
+/// Returns early with the given error if the condition is false.
+///
+/// This is the inverse of [`fail_if!`].
+///
+/// ```rust
+/// # use fallible_option::{ensure, Fallible::{self, Fail, Success}};
+/// fn check_temperature(celsius: i32) -> Result<i32, &'static str> {
+///     ensure!(celsius > -274, "below absolute zero");
+///     Ok(celsius + 273)
+/// }
+///
+/// assert_eq!(check_temperature(-300), Err("below absolute zero"));
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            $crate::fail!($err);
+        }
+    };
+}
+
+/// Returns early with the given error if the condition is true.
+///
+/// This is the inverse of [`ensure!`].
+///
+/// ```rust
+/// # use fallible_option::{fail_if, Fallible::{self, Fail, Success}};
+/// fn write(buffer: &[u8]) -> Fallible<&'static str> {
+///     fail_if!(buffer.is_empty(), "nothing to write");
+///     Success
+/// }
+///
+/// assert_eq!(write(&[]), Fail("nothing to write"));
+/// assert_eq!(write(&[1, 2, 3]), Success);
+/// ```
+#[macro_export]
+macro_rules! fail_if {
+    ($cond:expr, $err:expr $(,)?) => {
+        if $cond {
+            $crate::fail!($err);
+        }
+    };
+}
+
+/// Returns early with the given error if the two expressions are not equal.
+///
+/// Both expressions are evaluated exactly once, and compared by reference,
+/// the same way [`assert_eq!`] compares them.
+///
+/// ```rust
+/// # use fallible_option::{ensure_eq, Fallible::{self, Fail, Success}};
+/// fn check_checksum(expected: u32, data: &[u8]) -> Fallible<&'static str> {
+///     ensure_eq!(data.iter().map(|&b| u32::from(b)).sum::<u32>(), expected, "checksum mismatch");
+///     Success
+/// }
+///
+/// assert_eq!(check_checksum(6, &[1, 2, 3]), Success);
+/// assert_eq!(check_checksum(7, &[1, 2, 3]), Fail("checksum mismatch"));
+/// ```
+#[macro_export]
+macro_rules! ensure_eq {
+    ($left:expr, $right:expr, $err:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::fail!($err);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::ControlFlow;
+
+    use crate::Fallible::{self, Fail, Success};
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Negative,
+        TooLarge,
+        Mismatch,
+    }
+
+    fn check(n: i32, expected: i32) -> Fallible<Error> {
+        ensure!(n >= 0, Error::Negative);
+        fail_if!(n > 100, Error::TooLarge);
+        ensure_eq!(n, expected, Error::Mismatch);
+        Success
+    }
+
+    #[test]
+    fn fallible_context() {
+        assert_eq!(check(-1, -1), Fail(Error::Negative));
+        assert_eq!(check(101, 101), Fail(Error::TooLarge));
+        assert_eq!(check(10, 11), Fail(Error::Mismatch));
+        assert_eq!(check(10, 10), Success);
+    }
+
+    #[test]
+    fn result_context() {
+        fn halve(n: u32) -> Result<u32, &'static str> {
+            fail_if!(n % 2 == 1, "number is odd");
+            Ok(n / 2)
+        }
+
+        assert_eq!(halve(3), Err("number is odd"));
+        assert_eq!(halve(4), Ok(2));
+    }
+
+    #[test]
+    fn control_flow_context() {
+        fn visit(n: u32) -> ControlFlow<u32> {
+            ensure!(n < 10, n);
+            ControlFlow::Continue(())
+        }
+
+        assert_eq!(visit(12), ControlFlow::Break(12));
+        assert_eq!(visit(5), ControlFlow::Continue(()));
+    }
+
+    #[test]
+    fn error_conversion() {
+        #[derive(Debug, PartialEq)]
+        struct Outer(Error);
+
+        impl From<Error> for Outer {
+            fn from(err: Error) -> Self {
+                Outer(err)
+            }
+        }
+
+        fn convert() -> Fallible<Outer> {
+            fail!(Error::Negative)
+        }
+
+        assert_eq!(convert(), Fail(Outer(Error::Negative)));
+    }
+
+    #[test]
+    fn ensure_eq_evaluates_once() {
+        fn compare(counter: &mut u32) -> Fallible<u32> {
+            ensure_eq!(
+                {
+                    *counter += 1;
+                    *counter
+                },
+                2,
+                *counter
+            );
+            Success
+        }
+
+        let mut counter = 0;
+        assert_eq!(compare(&mut counter), Fail(1));
+        assert_eq!(compare(&mut counter), Success);
+        assert_eq!(counter, 2);
+    }
+}
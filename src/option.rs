@@ -0,0 +1,86 @@
+use crate::Fallible::{self, Fail, Success};
+
+/// Extension trait for adopting `Fallible` in code which uses `Option`.
+///
+/// Since `None` carries no error, applying `?` to an `Option` within a function
+/// returning `Fallible` is not supported. Instead, the absence of a value can be
+/// turned into a failure explicitly:
+///
+/// ```rust
+/// # use fallible_option::{OptionExt, Fallible::{self, Fail, Success}};
+/// fn requires_config(config: Option<&str>) -> Fallible<&'static str> {
+///     config.ok_or_fail("missing configuration")
+/// }
+///
+/// assert_eq!(requires_config(Some("verbose = true")), Success);
+/// assert_eq!(requires_config(None), Fail("missing configuration"));
+/// ```
+pub trait OptionExt<T> {
+    /// Transforms the `Option<T>` into a `Fallible<E>`, where `Some(_)` becomes
+    /// `Success`, and `None` becomes `Fail(err)`.
+    ///
+    /// Arguments passed to `ok_or_fail` are eagerly evaluated; if you are passing the
+    /// result of a function call, it is recommended to use [`ok_or_else_fail`](OptionExt::ok_or_else_fail),
+    /// which is lazily evaluated.
+    fn ok_or_fail<E>(self, err: E) -> Fallible<E>;
+
+    /// Transforms the `Option<T>` into a `Fallible<E>`, where `Some(_)` becomes
+    /// `Success`, and `None` becomes `Fail(err())`.
+    ///
+    /// ```rust
+    /// # use fallible_option::{OptionExt, Fallible::{self, Fail, Success}};
+    /// assert_eq!(Some(10).ok_or_else_fail(|| "no value"), Success);
+    /// assert_eq!(None::<u32>.ok_or_else_fail(|| "no value"), Fail("no value"));
+    /// ```
+    fn ok_or_else_fail<E, F>(self, err: F) -> Fallible<E>
+    where
+        F: FnOnce() -> E;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[inline]
+    fn ok_or_fail<E>(self, err: E) -> Fallible<E> {
+        match self {
+            Some(_) => Success,
+            None => Fail(err),
+        }
+    }
+
+    #[inline]
+    fn ok_or_else_fail<E, F>(self, err: F) -> Fallible<E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Some(_) => Success,
+            None => Fail(err()),
+        }
+    }
+}
+
+/// Extension trait for transposing an `Option<Fallible<E>>`.
+pub trait OptionFallibleExt<E> {
+    /// Transposes an `Option<Fallible<E>>` into a `Fallible<Option<E>>`.
+    ///
+    /// This is the inverse of [`Fallible::transpose`], where `Some(Success)` becomes
+    /// `Success`, `Some(Fail(e))` becomes `Fail(Some(e))`, and `None` becomes `Fail(None)`.
+    ///
+    /// ```rust
+    /// # use fallible_option::{OptionFallibleExt, Fallible::{self, Fail, Success}};
+    /// assert_eq!(Some(Fail("uh oh!")).transpose_fallible(), Fail(Some("uh oh!")));
+    /// assert_eq!(None::<Fallible<&str>>.transpose_fallible(), Fail(None));
+    /// assert_eq!(Some(Success::<&str>).transpose_fallible(), Success);
+    /// ```
+    fn transpose_fallible(self) -> Fallible<Option<E>>;
+}
+
+impl<E> OptionFallibleExt<E> for Option<Fallible<E>> {
+    #[inline]
+    fn transpose_fallible(self) -> Fallible<Option<E>> {
+        match self {
+            Some(Success) => Success,
+            Some(Fail(e)) => Fail(Some(e)),
+            None => Fail(None),
+        }
+    }
+}
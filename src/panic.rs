@@ -0,0 +1,259 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::fmt::{self, Debug, Display};
+use std::panic::{self, UnwindSafe};
+use std::string::String;
+use std::thread::{JoinHandle, ScopedJoinHandle};
+
+use crate::Fallible::{self, Fail};
+
+/// Failure of an operation which may either fail with an error `E`, or panic.
+///
+/// Produced by [`Fallible::catch_unwind`], and by joining threads through [`JoinHandleExt`].
+///
+/// ```rust
+/// # use fallible_option::{Panicked, Fallible::{self, Fail, Success}};
+/// fn plugin(input: u32) -> Fallible<&'static str> {
+///     match input {
+///         0 => Fail("input is zero"),
+///         1 => panic!("plugin crashed"),
+///         _ => Success,
+///     }
+/// }
+///
+/// let outcome = Fallible::catch_unwind(|| plugin(0));
+/// assert!(matches!(outcome, Fail(Panicked::Failed("input is zero"))));
+///
+/// let outcome = Fallible::catch_unwind(|| plugin(1));
+/// assert_eq!(outcome.unwrap_fail().message(), Some("plugin crashed"));
+/// ```
+pub enum Panicked<E> {
+    /// The operation completed, and failed with an error.
+    Failed(E),
+    /// The operation panicked, with the given payload.
+    Panic(Box<dyn Any + Send + 'static>),
+}
+
+impl<E> Panicked<E> {
+    /// Returns true if the operation panicked.
+    #[inline]
+    pub fn is_panic(&self) -> bool {
+        matches!(self, Panicked::Panic(_))
+    }
+
+    /// Returns the message of the panic, if the operation panicked, and the panic
+    /// payload was a `&str` or `String`, as produced by [`panic!`].
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Panicked::Failed(_) => None,
+            Panicked::Panic(payload) => payload
+                .downcast_ref::<&'static str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str)),
+        }
+    }
+
+    /// Returns the error of the operation, if it did not panic.
+    #[inline]
+    pub fn into_error(self) -> Option<E> {
+        match self {
+            Panicked::Failed(e) => Some(e),
+            Panicked::Panic(_) => None,
+        }
+    }
+
+    /// Returns the error of the operation, resuming the panic if the operation panicked.
+    ///
+    /// See [`std::panic::resume_unwind`].
+    #[inline]
+    pub fn resume_unwind(self) -> E {
+        match self {
+            Panicked::Failed(e) => e,
+            Panicked::Panic(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+impl<E> Debug for Panicked<E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Panicked::Failed(e) => f.debug_tuple("Failed").field(e).finish(),
+            Panicked::Panic(_) => match self.message() {
+                Some(message) => f.debug_tuple("Panic").field(&message).finish(),
+                None => f.debug_tuple("Panic").finish_non_exhaustive(),
+            },
+        }
+    }
+}
+
+impl<E> Display for Panicked<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Panicked::Failed(e) => Display::fmt(e, f),
+            Panicked::Panic(_) => match self.message() {
+                Some(message) => write!(f, "panicked: {message}"),
+                None => f.write_str("panicked"),
+            },
+        }
+    }
+}
+
+impl<E> std::error::Error for Panicked<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Panicked::Failed(e) => e.source(),
+            Panicked::Panic(_) => None,
+        }
+    }
+}
+
+impl<E> From<Box<dyn Any + Send + 'static>> for Panicked<E> {
+    #[inline]
+    fn from(payload: Box<dyn Any + Send + 'static>) -> Self {
+        Panicked::Panic(payload)
+    }
+}
+
+/// The following functions are only available if the `std` feature is enabled.
+impl<E> Fallible<Panicked<E>> {
+    /// Invokes `f`, capturing both a `Fail` returned by it, and any panic raised
+    /// while running it.
+    ///
+    /// Like [`std::panic::catch_unwind`], this only catches unwinding panics, and the
+    /// panic hook is still invoked before the panic is caught.
+    #[inline]
+    pub fn catch_unwind<F>(f: F) -> Self
+    where
+        F: FnOnce() -> Fallible<E> + UnwindSafe,
+    {
+        match panic::catch_unwind(f) {
+            Ok(outcome) => outcome.map(Panicked::Failed),
+            Err(payload) => Fail(Panicked::Panic(payload)),
+        }
+    }
+}
+
+/// Extension trait for joining a thread which produces a `Fallible<E>`.
+pub trait JoinHandleExt<E> {
+    /// Waits for the thread to finish, producing a `Fail` if the thread failed,
+    /// or panicked.
+    ///
+    /// ```rust
+    /// # use fallible_option::{JoinHandleExt, Fallible::{self, Fail, Success}};
+    /// let handle = std::thread::spawn(|| -> Fallible<&'static str> {
+    ///     panic!("worker crashed");
+    /// });
+    ///
+    /// assert_eq!(handle.join_fallible().unwrap_fail().message(), Some("worker crashed"));
+    /// ```
+    fn join_fallible(self) -> Fallible<Panicked<E>>;
+}
+
+impl<E> JoinHandleExt<E> for JoinHandle<Fallible<E>> {
+    #[inline]
+    fn join_fallible(self) -> Fallible<Panicked<E>> {
+        joined(self.join())
+    }
+}
+
+impl<E> JoinHandleExt<E> for ScopedJoinHandle<'_, Fallible<E>> {
+    #[inline]
+    fn join_fallible(self) -> Fallible<Panicked<E>> {
+        joined(self.join())
+    }
+}
+
+fn joined<E>(result: std::thread::Result<Fallible<E>>) -> Fallible<Panicked<E>> {
+    match result {
+        Ok(outcome) => outcome.map(Panicked::Failed),
+        Err(payload) => Fail(Panicked::Panic(payload)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::format;
+    use std::string::ToString;
+
+    use crate::{
+        fallible_try,
+        Fallible::{self, Fail, Success},
+        JoinHandleExt, Panicked,
+    };
+
+    fn plugin(input: u32) -> Fallible<&'static str> {
+        match input {
+            0 => Fail("input is zero"),
+            1 => panic!("static message"),
+            2 => panic!("formatted message {input}"),
+            3 => std::panic::panic_any(input),
+            _ => Success,
+        }
+    }
+
+    #[test]
+    fn catch_unwind() {
+        assert!(Fallible::catch_unwind(|| plugin(10)).is_successful());
+
+        let failed = Fallible::catch_unwind(|| plugin(0)).unwrap_fail();
+        assert!(!failed.is_panic());
+        assert_eq!(failed.message(), None);
+        assert_eq!(failed.to_string(), "input is zero");
+        assert_eq!(failed.into_error(), Some("input is zero"));
+
+        let panicked = Fallible::catch_unwind(|| plugin(1)).unwrap_fail();
+        assert!(panicked.is_panic());
+        assert_eq!(panicked.to_string(), "panicked: static message");
+
+        let panicked = Fallible::catch_unwind(|| plugin(2)).unwrap_fail();
+        assert_eq!(panicked.message(), Some("formatted message 2"));
+        assert_eq!(format!("{panicked:?}"), r#"Panic("formatted message 2")"#);
+
+        let panicked = Fallible::catch_unwind(|| plugin(3)).unwrap_fail();
+        assert_eq!(panicked.message(), None);
+        assert_eq!(panicked.to_string(), "panicked");
+        assert_eq!(format!("{panicked:?}"), "Panic(..)");
+    }
+
+    #[test]
+    fn join_fallible() {
+        let outcome = std::thread::spawn(|| plugin(0)).join_fallible();
+        assert!(matches!(outcome, Fail(Panicked::Failed("input is zero"))));
+
+        std::thread::scope(|scope| {
+            let outcome = scope.spawn(|| plugin(2)).join_fallible();
+            assert_eq!(outcome.unwrap_fail().message(), Some("formatted message 2"));
+        });
+    }
+
+    #[test]
+    fn resume_unwind() {
+        let payload = std::panic::catch_unwind(|| {
+            Fallible::catch_unwind(|| plugin(1)).map(Panicked::resume_unwind)
+        })
+        .unwrap_err();
+
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"static message"));
+    }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn run() -> Result<u32, Panicked<&'static str>> {
+            fallible_try!(Fallible::catch_unwind(|| plugin(10)));
+            fallible_try!(Fallible::catch_unwind(|| plugin(1)));
+            Ok(10)
+        }
+
+        assert!(run().unwrap_err().is_panic());
+    }
+}
@@ -0,0 +1,278 @@
+//! Running processes whose only outcome is whether or not they succeeded.
+//!
+//! ```rust
+//! # #[cfg(unix)] {
+//! # use fallible_option::process::CommandExt;
+//! use std::process::Command;
+//!
+//! assert!(Command::new("true").run_fallible().is_successful());
+//!
+//! let outcome = Command::new("sh")
+//!     .args(["-c", "echo 'disk is full' >&2; exit 3"])
+//!     .run_fallible_capturing(1024);
+//!
+//! assert_eq!(outcome.unwrap_fail().to_string(), "process exited with code 3: disk is full");
+//! # }
+//! ```
+
+use std::fmt::{self, Display};
+use std::io::{self, ErrorKind, Read};
+use std::process::{Command, ExitStatus, Stdio};
+use std::string::String;
+use std::vec::Vec;
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Error produced when running a [`Command`] through [`CommandExt`].
+#[derive(Debug)]
+pub enum CommandError {
+    /// The process could not be spawned.
+    ///
+    /// [`CommandExt::run_fallible`] also reports a failure to wait on the process
+    /// this way, since [`Command::status`] does not distinguish the two.
+    Spawn(io::Error),
+    /// The process was spawned, but reading its standard error, or waiting for it
+    /// to exit, failed.
+    Io(io::Error),
+    /// The process exited with a non-zero exit code.
+    Exit {
+        /// The exit code of the process.
+        code: i32,
+        /// The captured tail of the standard error of the process, if it was captured.
+        stderr: Option<String>,
+    },
+    /// The process was terminated by a signal.
+    ///
+    /// This is only ever produced on Unix platforms.
+    Signal {
+        /// The number of the signal which terminated the process.
+        signal: i32,
+        /// The captured tail of the standard error of the process, if it was captured.
+        stderr: Option<String>,
+    },
+}
+
+impl CommandError {
+    /// Returns the captured standard error of the process, if any was captured.
+    #[inline]
+    pub fn stderr(&self) -> Option<&str> {
+        match self {
+            CommandError::Spawn(_) | CommandError::Io(_) => None,
+            CommandError::Exit { stderr, .. } | CommandError::Signal { stderr, .. } => {
+                stderr.as_deref()
+            }
+        }
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Spawn(err) => return write!(f, "failed to run process: {err}"),
+            CommandError::Io(err) => return write!(f, "I/O error while running process: {err}"),
+            CommandError::Exit { code, .. } => write!(f, "process exited with code {code}")?,
+            CommandError::Signal { signal, .. } => {
+                write!(f, "process was terminated by signal {signal}")?
+            }
+        }
+
+        match self.stderr().map(str::trim_end) {
+            Some(stderr) if !stderr.is_empty() => write!(f, ": {stderr}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CommandError::Spawn(err) | CommandError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Extension trait for running a [`Command`] to completion, producing a `Fallible<CommandError>`.
+pub trait CommandExt {
+    /// Runs the command to completion, failing if it could not be spawned,
+    /// or did not exit successfully.
+    ///
+    /// The standard streams are inherited from the current process, as with [`Command::status`].
+    fn run_fallible(&mut self) -> Fallible<CommandError>;
+
+    /// Runs the command to completion like [`CommandExt::run_fallible`], but captures the
+    /// standard error of the process, and includes it in the error if the process fails.
+    ///
+    /// Only the last `limit` bytes written to standard error are retained, and any
+    /// invalid UTF-8 is replaced.
+    ///
+    /// This replaces the standard error configuration of the command: once the process
+    /// has been spawned, standard error is set to [`Stdio::inherit`], regardless of how
+    /// it was configured before, such that running the command again does not capture
+    /// it. Configure standard error again with [`Command::stderr`] if needed.
+    fn run_fallible_capturing(&mut self, limit: usize) -> Fallible<CommandError>;
+}
+
+impl CommandExt for Command {
+    fn run_fallible(&mut self) -> Fallible<CommandError> {
+        match self.status() {
+            Ok(status) => check_status(status, None),
+            Err(err) => Fail(CommandError::Spawn(err)),
+        }
+    }
+
+    fn run_fallible_capturing(&mut self, limit: usize) -> Fallible<CommandError> {
+        let spawned = self.stderr(Stdio::piped()).spawn();
+        let _ = self.stderr(Stdio::inherit());
+
+        let mut child = match spawned {
+            Ok(child) => child,
+            Err(err) => return Fail(CommandError::Spawn(err)),
+        };
+
+        let mut captured = Vec::new();
+        if let Some(mut stderr) = child.stderr.take() {
+            let mut buffer = [0; 4096];
+            loop {
+                match stderr.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(read) => {
+                        captured.extend_from_slice(&buffer[..read]);
+                        if captured.len() > limit {
+                            let _ = captured.drain(..captured.len() - limit);
+                        }
+                    }
+                    Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                    Err(err) => {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        return Fail(CommandError::Io(err));
+                    }
+                }
+            }
+        }
+
+        match child.wait() {
+            Ok(status) => check_status(
+                status,
+                Some(String::from_utf8_lossy(&captured).into_owned()),
+            ),
+            Err(err) => Fail(CommandError::Io(err)),
+        }
+    }
+}
+
+fn check_status(status: ExitStatus, stderr: Option<String>) -> Fallible<CommandError> {
+    if status.success() {
+        return Success;
+    }
+
+    if let Some(code) = status.code() {
+        return Fail(CommandError::Exit { code, stderr });
+    }
+
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return Fail(CommandError::Signal { signal, stderr });
+    }
+
+    // Non-unix platforms always report an exit code.
+    unreachable!("process exited without an exit code or signal")
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::process::{Command, Stdio};
+    use std::string::ToString;
+
+    use super::{CommandError, CommandExt};
+    use crate::fallible_try;
+
+    fn sh(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        let _ = command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn exit_codes() {
+        assert!(Command::new("true").run_fallible().is_successful());
+
+        match Command::new("false").run_fallible().unwrap_fail() {
+            CommandError::Exit { code, stderr } => {
+                assert_eq!(code, 1);
+                assert_eq!(stderr, None);
+            }
+            err => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn spawn_failure() {
+        let err = Command::new("fallible-option-does-not-exist")
+            .run_fallible()
+            .unwrap_fail();
+
+        assert!(matches!(err, CommandError::Spawn(_)));
+        assert!(std::error::Error::source(&err).is_some());
+
+        let err = CommandError::Io(std::io::Error::other("broken pipe"));
+        assert_eq!(
+            err.to_string(),
+            "I/O error while running process: broken pipe"
+        );
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn signal() {
+        let err = sh("kill -9 $$").run_fallible().unwrap_fail();
+
+        assert!(matches!(err, CommandError::Signal { signal: 9, .. }));
+        assert_eq!(err.to_string(), "process was terminated by signal 9");
+    }
+
+    #[test]
+    fn bounded_capture() {
+        let err = sh("echo first >&2; echo second >&2; exit 4")
+            .run_fallible_capturing(7)
+            .unwrap_fail();
+
+        assert_eq!(err.stderr(), Some("second\n"));
+        assert_eq!(err.to_string(), "process exited with code 4: second");
+
+        let outcome = sh("echo ignored >&2").run_fallible_capturing(1024);
+        assert!(outcome.is_successful());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn capture_does_not_persist() {
+        // Succeeds only if the standard error of the process is that of the test.
+        let stderr = std::fs::read_link("/proc/self/fd/2").unwrap();
+        let script = std::format!(
+            r#"test "$(readlink /proc/$$/fd/2)" = '{}'"#,
+            stderr.display()
+        );
+
+        let mut command = sh(&script);
+        assert!(command.run_fallible_capturing(1024).is_fail());
+        assert!(command.run_fallible().is_successful());
+
+        // A previously configured standard error is replaced as well.
+        let _ = command.stderr(Stdio::null());
+        assert!(command.run_fallible_capturing(1024).is_fail());
+        assert!(command.run_fallible().is_successful());
+    }
+
+    #[test]
+    fn fallible_try_conversion() {
+        fn build() -> Result<u32, CommandError> {
+            fallible_try!(sh("exit 0").run_fallible());
+            fallible_try!(sh("exit 2").run_fallible());
+            Ok(10)
+        }
+
+        assert!(matches!(build(), Err(CommandError::Exit { code: 2, .. })));
+    }
+}
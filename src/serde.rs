@@ -0,0 +1,275 @@
+//! Alternative [serde](https://serde.rs) representations of [`Fallible`].
+//!
+//! By default, `Fallible<E>` is serialized in the externally tagged representation,
+//! as either `"Success"` or `{"Fail": ...}`. The modules within can be used with
+//! `#[serde(with = "...")]` to select a different representation for a field:
+//!
+//! ```rust
+//! # use fallible_option::Fallible::{self, Fail, Success};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Acknowledgement {
+//!     #[serde(with = "fallible_option::serde::nullable")]
+//!     outcome: Fallible<String>,
+//! }
+//!
+//! let ack = Acknowledgement { outcome: Fail("timed out".to_owned()) };
+//!
+//! assert_eq!(serde_json::to_string(&ack).unwrap(), r#"{"outcome":"timed out"}"#);
+//! ```
+
+/// Represents `Success` as `null`, and `Fail(e)` as the serialized form of `e`.
+///
+/// This is the same representation `serde` uses for an `Option<E>`.
+///
+/// The representation is lossy if `E` itself may be serialized as `null`, such as `()`
+/// or an `Option<T>`: `Fail(None)` is serialized as `null`, and is then deserialized as
+/// `Success`. Use [`result`](super::result) for such errors instead.
+///
+/// ```rust
+/// # use fallible_option::Fallible::{self, Fail, Success};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Acknowledgement {
+///     #[serde(with = "fallible_option::serde::nullable")]
+///     outcome: Fallible<u32>,
+/// }
+///
+/// let ack: Acknowledgement = serde_json::from_str(r#"{"outcome":null}"#).unwrap();
+/// assert_eq!(ack, Acknowledgement { outcome: Success });
+/// ```
+pub mod nullable {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Fallible::{self, Fail, Success};
+
+    /// Serializes `Success` as `null`, and `Fail(e)` as `e`.
+    pub fn serialize<E, S>(value: &Fallible<E>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        E: Serialize,
+        S: Serializer,
+    {
+        match value {
+            Success => serializer.serialize_none(),
+            Fail(e) => serializer.serialize_some(e),
+        }
+    }
+
+    /// Deserializes `null` as `Success`, and any other value as `Fail`.
+    pub fn deserialize<'de, E, D>(deserializer: D) -> Result<Fallible<E>, D::Error>
+    where
+        E: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(match Option::<E>::deserialize(deserializer)? {
+            None => Success,
+            Some(e) => Fail(e),
+        })
+    }
+}
+
+/// Represents `Fallible<E>` the same way `serde` represents a `Result<(), E>`,
+/// as either `{"Ok": null}` or `{"Err": ...}`.
+///
+/// ```rust
+/// # use fallible_option::Fallible::{self, Fail, Success};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Acknowledgement {
+///     #[serde(with = "fallible_option::serde::result")]
+///     outcome: Fallible<u32>,
+/// }
+///
+/// let ack = Acknowledgement { outcome: Success };
+/// assert_eq!(serde_json::to_string(&ack).unwrap(), r#"{"outcome":{"Ok":null}}"#);
+///
+/// let ack: Acknowledgement = serde_json::from_str(r#"{"outcome":{"Err":404}}"#).unwrap();
+/// assert_eq!(ack, Acknowledgement { outcome: Fail(404) });
+/// ```
+pub mod result {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Fallible;
+
+    /// Serializes `Success` as `Ok(())`, and `Fail(e)` as `Err(e)`.
+    pub fn serialize<E, S>(value: &Fallible<E>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        E: Serialize,
+        S: Serializer,
+    {
+        value.as_ref().result().serialize(serializer)
+    }
+
+    /// Deserializes `Ok(())` as `Success`, and `Err(e)` as `Fail(e)`.
+    pub fn deserialize<'de, E, D>(deserializer: D) -> Result<Fallible<E>, D::Error>
+    where
+        E: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Result::<(), E>::deserialize(deserializer).map(Fallible::from)
+    }
+}
+
+/// Represents `Fallible<E>` as a struct with an `ok` flag, and a nullable `error` field,
+/// as either `{"ok": true, "error": null}` or `{"ok": false, "error": ...}`.
+///
+/// When deserializing, the `error` field may be omitted for successful outcomes, but
+/// an `ok` flag which disagrees with the presence of an `error` is rejected.
+///
+/// ```rust
+/// # use fallible_option::Fallible::{self, Fail, Success};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Acknowledgement {
+///     #[serde(with = "fallible_option::serde::flattened")]
+///     outcome: Fallible<u32>,
+/// }
+///
+/// let ack = Acknowledgement { outcome: Fail(404) };
+/// assert_eq!(
+///     serde_json::to_string(&ack).unwrap(),
+///     r#"{"outcome":{"ok":false,"error":404}}"#
+/// );
+///
+/// let ack: Acknowledgement = serde_json::from_str(r#"{"outcome":{"ok":true}}"#).unwrap();
+/// assert_eq!(ack, Acknowledgement { outcome: Success });
+/// ```
+pub mod flattened {
+    use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Fallible::{self, Fail, Success};
+
+    #[derive(Serialize)]
+    #[serde(rename = "Fallible")]
+    struct Flattened<'a, E> {
+        ok: bool,
+        error: Option<&'a E>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Fallible")]
+    struct Owned<E> {
+        ok: bool,
+        error: Option<E>,
+    }
+
+    /// Serializes `Success` as `{"ok": true, "error": null}`, and
+    /// `Fail(e)` as `{"ok": false, "error": e}`.
+    pub fn serialize<E, S>(value: &Fallible<E>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        E: Serialize,
+        S: Serializer,
+    {
+        Flattened {
+            ok: value.is_successful(),
+            error: value.err(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes `{"ok": true}` as `Success`, and `{"ok": false, "error": e}` as `Fail(e)`.
+    pub fn deserialize<'de, E, D>(deserializer: D) -> Result<Fallible<E>, D::Error>
+    where
+        E: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        match Owned::<E>::deserialize(deserializer)? {
+            Owned {
+                ok: true,
+                error: None,
+            } => Ok(Success),
+            Owned {
+                ok: false,
+                error: Some(e),
+            } => Ok(Fail(e)),
+            Owned { ok: true, .. } => Err(D::Error::custom("successful outcome contains an error")),
+            Owned { ok: false, .. } => Err(D::Error::custom("failed outcome is missing an error")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::Fallible::{self, Fail, Success};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Outcomes {
+        tagged: Fallible<u32>,
+        #[serde(with = "crate::serde::nullable")]
+        nullable: Fallible<u32>,
+        #[serde(with = "crate::serde::result")]
+        result: Fallible<u32>,
+        #[serde(with = "crate::serde::flattened")]
+        flattened: Fallible<u32>,
+    }
+
+    #[test]
+    fn successful_round_trip() {
+        let outcomes = Outcomes {
+            tagged: Success,
+            nullable: Success,
+            result: Success,
+            flattened: Success,
+        };
+
+        let json = serde_json::to_string(&outcomes).unwrap();
+        assert_eq!(
+            json,
+            r#"{"tagged":"Success","nullable":null,"result":{"Ok":null},"flattened":{"ok":true,"error":null}}"#
+        );
+        assert_eq!(serde_json::from_str::<Outcomes>(&json).unwrap(), outcomes);
+    }
+
+    #[test]
+    fn failed_round_trip() {
+        let outcomes = Outcomes {
+            tagged: Fail(1),
+            nullable: Fail(2),
+            result: Fail(3),
+            flattened: Fail(4),
+        };
+
+        let json = serde_json::to_string(&outcomes).unwrap();
+        assert_eq!(
+            json,
+            r#"{"tagged":{"Fail":1},"nullable":2,"result":{"Err":3},"flattened":{"ok":false,"error":4}}"#
+        );
+        assert_eq!(serde_json::from_str::<Outcomes>(&json).unwrap(), outcomes);
+    }
+
+    #[test]
+    fn flattened_rejects_inconsistent_outcomes() {
+        #[derive(Deserialize, Debug)]
+        struct Flattened {
+            #[serde(with = "crate::serde::flattened")]
+            _outcome: Fallible<u32>,
+        }
+
+        assert!(
+            serde_json::from_str::<Flattened>(r#"{"_outcome":{"ok":true,"error":1}}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Flattened>(r#"{"_outcome":{"ok":false}}"#).is_err());
+    }
+
+    #[test]
+    fn nullable_is_lossy_for_nullable_errors() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Nullable {
+            #[serde(with = "crate::serde::nullable")]
+            outcome: Fallible<Option<u32>>,
+        }
+
+        let failed = Nullable {
+            outcome: Fail(None),
+        };
+        let json = serde_json::to_string(&failed).unwrap();
+
+        assert_eq!(json, r#"{"outcome":null}"#);
+        assert_eq!(
+            serde_json::from_str::<Nullable>(&json).unwrap(),
+            Nullable { outcome: Success }
+        );
+    }
+}
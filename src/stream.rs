@@ -0,0 +1,257 @@
+//! Combinators for streams of [`Fallible`] items.
+//!
+//! ```rust
+//! # use fallible_option::{stream::FallibleStreamExt, Fallible::{self, Fail, Success}};
+//! # use futures_core::Stream;
+//! # use std::{pin::Pin, task::{Context, Poll}};
+//! # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+//! #     let mut future = std::pin::pin!(future);
+//! #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+//! #     loop {
+//! #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+//! #             return output;
+//! #         }
+//! #     }
+//! # }
+//! # struct Iter<I>(I);
+//! # impl<I: Iterator + Unpin> Stream for Iter<I> {
+//! #     type Item = I::Item;
+//! #     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
+//! #         Poll::Ready(self.0.next())
+//! #     }
+//! # }
+//! # fn acknowledgements() -> impl Stream<Item = Fallible<&'static str>> {
+//! #     Iter([Success, Fail("rejected"), Fail("timed out")].into_iter())
+//! # }
+//! // A stream of acknowledgements, each of which may have failed.
+//! let acks = acknowledgements();
+//!
+//! assert_eq!(block_on(acks.first_fail()), Fail("rejected"));
+//! ```
+
+use core::future::{poll_fn, Future};
+use core::pin::{pin, Pin};
+use core::task::{Context, Poll};
+
+use futures_core::{FusedStream, Stream};
+
+use crate::Fallible::{self, Fail, Success};
+
+/// Extension trait for streams of `Fallible<E>` items.
+///
+/// This trait is implemented for every `Stream<Item = Fallible<E>>`.
+pub trait FallibleStreamExt<E>: Stream<Item = Fallible<E>> {
+    /// Drains the stream, resolving to the first `Fail` it produces, or to `Success`
+    /// if the stream ends without producing one.
+    ///
+    /// The stream is not polled any further once a `Fail` has been produced,
+    /// mirroring how a `Fallible` is collected from an iterator.
+    fn first_fail(self) -> impl Future<Output = Fallible<E>>
+    where
+        Self: Sized,
+    {
+        async move {
+            let mut stream = pin!(self);
+
+            while let Some(outcome) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                if let Fail(e) = outcome {
+                    return Fail(e);
+                }
+            }
+
+            Success
+        }
+    }
+
+    /// Drains the stream, accumulating the errors of every `Fail` it produces
+    /// into a [`Failures`](crate::Failures).
+    #[cfg(feature = "alloc")]
+    fn collect_fails<C>(self) -> impl Future<Output = crate::Failures<E, C>>
+    where
+        Self: Sized,
+        C: Default + Extend<E>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            let mut failures = crate::Failures::new();
+
+            while let Some(outcome) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                failures.push(outcome);
+            }
+
+            failures
+        }
+    }
+
+    /// Adapts the stream to end immediately after yielding its first `Fail`.
+    fn fail_fast(self) -> FailFast<Self>
+    where
+        Self: Sized + Unpin,
+    {
+        FailFast {
+            stream: self,
+            done: false,
+        }
+    }
+}
+
+impl<E, S> FallibleStreamExt<E> for S where S: Stream<Item = Fallible<E>> {}
+
+/// Stream which ends immediately after yielding the first `Fail` of the underlying stream.
+///
+/// This `struct` is created by the [`FallibleStreamExt::fail_fast`] function.
+#[must_use = "streams do nothing unless polled"]
+#[derive(Debug)]
+pub struct FailFast<S> {
+    stream: S,
+    done: bool,
+}
+
+impl<E, S> Stream for FailFast<S>
+where
+    S: Stream<Item = Fallible<E>> + Unpin,
+{
+    type Item = Fallible<E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fallible<E>>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        match Pin::new(&mut this.stream).poll_next(cx) {
+            Poll::Ready(Some(Fail(e))) => {
+                this.done = true;
+                Poll::Ready(Some(Fail(e)))
+            }
+            Poll::Ready(None) => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+            poll => poll,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
+
+impl<E, S> FusedStream for FailFast<S>
+where
+    S: Stream<Item = Fallible<E>> + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::future::{poll_fn, Future};
+    use core::pin::{pin, Pin};
+    use core::task::{Context, Poll, Waker};
+
+    use futures_core::{FusedStream, Stream};
+
+    use super::FallibleStreamExt;
+    use crate::Fallible::{self, Fail, Success};
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// Stream which yields each outcome on alternating polls, counting how often it is polled.
+    struct Outcomes<'a> {
+        outcomes: &'a [Fallible<u32>],
+        polled: &'a Cell<usize>,
+        ready: bool,
+    }
+
+    impl<'a> Outcomes<'a> {
+        fn new(outcomes: &'a [Fallible<u32>], polled: &'a Cell<usize>) -> Self {
+            Outcomes {
+                outcomes,
+                polled,
+                ready: false,
+            }
+        }
+    }
+
+    impl Stream for Outcomes<'_> {
+        type Item = Fallible<u32>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.polled.set(self.polled.get() + 1);
+            self.ready = !self.ready;
+            if !self.ready {
+                return Poll::Pending;
+            }
+
+            match self.outcomes.split_first() {
+                Some((first, rest)) => {
+                    self.outcomes = rest;
+                    Poll::Ready(Some(*first))
+                }
+                None => Poll::Ready(None),
+            }
+        }
+    }
+
+    const OUTCOMES: [Fallible<u32>; 4] = [Success, Fail(1), Success, Fail(3)];
+
+    #[test]
+    fn first_fail_stops_polling() {
+        let polled = Cell::new(0);
+        let outcome = block_on(Outcomes::new(&OUTCOMES, &polled).first_fail());
+
+        assert_eq!(outcome, Fail(1));
+        assert_eq!(polled.get(), 3);
+    }
+
+    #[test]
+    fn first_fail_succeeds() {
+        let polled = Cell::new(0);
+        let outcome = block_on(Outcomes::new(&[Success, Success], &polled).first_fail());
+
+        assert_eq!(outcome, Success);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect_fails() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let polled = Cell::new(0);
+        let failures = block_on(Outcomes::new(&OUTCOMES, &polled).collect_fails::<Vec<_>>());
+
+        assert_eq!(failures.into_fallible(), Fail(vec![1, 3]));
+    }
+
+    #[test]
+    fn fail_fast() {
+        let polled = Cell::new(0);
+        let mut stream = Outcomes::new(&OUTCOMES, &polled).fail_fast();
+        let mut next = || block_on(poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)));
+
+        assert_eq!(next(), Some(Success));
+        assert_eq!(next(), Some(Fail(1)));
+        assert_eq!(next(), None);
+        assert_eq!(next(), None);
+        assert_eq!(polled.get(), 3);
+        assert!(stream.is_terminated());
+    }
+}
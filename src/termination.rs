@@ -0,0 +1,102 @@
+use core::fmt::Debug;
+use std::eprintln;
+use std::process::{ExitCode, Termination};
+
+use crate::Fallible::{self, Fail, Success};
+
+/// The following functions are only available if the `std` feature is enabled,
+/// and the generic parameter `E` implements [`Debug`]
+impl<E> Fallible<E>
+where
+    E: Debug,
+{
+    /// Reports the outcome as an [`ExitCode`], printing the [`Debug`] representation
+    /// of the contained error to stderr, if any.
+    ///
+    /// `Success` is reported as [`ExitCode::SUCCESS`], while the exit code of a `Fail`
+    /// is determined by calling `f` with the contained error. This allows binaries to
+    /// map specific errors to specific exit codes:
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// use std::process::ExitCode;
+    ///
+    /// #[derive(Debug)]
+    /// enum CliError {
+    ///     InvalidArguments,
+    ///     Io(std::io::Error),
+    /// }
+    ///
+    /// fn report(outcome: Fallible<CliError>) -> ExitCode {
+    ///     outcome.report_with(|err| match err {
+    ///         CliError::InvalidArguments => ExitCode::from(2),
+    ///         CliError::Io(_) => ExitCode::FAILURE,
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(report(Fail(CliError::InvalidArguments)), ExitCode::from(2));
+    /// assert_eq!(report(Success), ExitCode::SUCCESS);
+    /// ```
+    #[inline]
+    pub fn report_with<F>(self, f: F) -> ExitCode
+    where
+        F: FnOnce(&E) -> ExitCode,
+    {
+        match self {
+            Success => ExitCode::SUCCESS,
+            Fail(e) => {
+                eprintln!("Error: {e:?}");
+                f(&e)
+            }
+        }
+    }
+}
+
+impl<E> Termination for Fallible<E>
+where
+    E: Debug,
+{
+    /// Allows `main` to return a `Fallible<E>`.
+    ///
+    /// `Success` is reported as [`ExitCode::SUCCESS`], while a `Fail` prints the [`Debug`]
+    /// representation of the contained error to stderr, and is reported as [`ExitCode::FAILURE`].
+    /// Use [`Fallible::report_with`] to report specific errors with specific exit codes.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail, Success};
+    /// fn main() -> Fallible<&'static str> {
+    ///     Success
+    /// }
+    /// ```
+    #[inline]
+    fn report(self) -> ExitCode {
+        self.report_with(|_| ExitCode::FAILURE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::{ExitCode, Termination};
+
+    use crate::Fallible::{self, Fail, Success};
+
+    #[test]
+    fn report_success() {
+        assert_eq!(Success::<&str>.report(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn report_fail() {
+        assert_eq!(Fail("something went wrong").report(), ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn report_with_custom_exit_code() {
+        let fallible: Fallible<u8> = Fail(3);
+
+        assert_eq!(
+            fallible.report_with(|code| ExitCode::from(*code)),
+            ExitCode::from(3)
+        );
+    }
+}
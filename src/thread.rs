@@ -0,0 +1,314 @@
+//! Running many `Fallible` tasks in parallel on scoped threads.
+//!
+//! ```rust
+//! # use fallible_option::{thread::{self, Policy}, Fallible::{self, Fail, Success}};
+//! fn check_disk() -> Fallible<&'static str> {
+//!     Success
+//! }
+//!
+//! fn check_network() -> Fallible<&'static str> {
+//!     Fail("host is unreachable")
+//! }
+//!
+//! let outcome = thread::scope(Policy::CollectAll, |scope| {
+//!     scope.spawn(|_| check_disk());
+//!     scope.spawn(|_| check_network());
+//! });
+//!
+//! let failures = outcome.unwrap_fail();
+//! assert_eq!(failures.len(), 1);
+//! assert_eq!(failures[0].to_string(), "host is unreachable");
+//! ```
+
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::Scope as StdScope;
+use std::vec::Vec;
+
+use crate::{
+    Fallible::{self, Fail, Success},
+    Panicked,
+};
+
+/// Determines how [`scope`] reacts to a failing task.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Policy {
+    /// Cancels the remaining tasks once any task fails, and reports only that failure.
+    ///
+    /// Tasks which have not yet started when a task fails are skipped, while running
+    /// tasks are expected to observe their [`Cancellation`] and stop early. Tasks are
+    /// never skipped because of a manual [`Cancellation::cancel`].
+    FailFast,
+    /// Runs every task to completion, and reports every failure.
+    CollectAll,
+}
+
+/// Cancellation flag shared by every task within a [`scope`].
+#[derive(Clone, Debug)]
+pub struct Cancellation {
+    flag: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    /// Returns true if the scope has been cancelled, and the task should stop early.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Acquire)
+    }
+
+    /// Cancels the scope, signalling every other task to stop early.
+    ///
+    /// This does not cause the scope to fail by itself.
+    #[inline]
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Release)
+    }
+}
+
+#[derive(Debug)]
+struct Shared<E> {
+    policy: Policy,
+    cancelled: Arc<AtomicBool>,
+    /// Set once a failure has been recorded under [`Policy::FailFast`], unlike `cancelled`
+    /// which may also be set through [`Cancellation::cancel`].
+    failed: AtomicBool,
+    failures: Mutex<Vec<Panicked<E>>>,
+}
+
+/// A scope for spawning `Fallible` tasks, created by [`scope`].
+#[derive(Debug)]
+pub struct Scope<'scope, 'env: 'scope, E> {
+    inner: &'scope StdScope<'scope, 'env>,
+    shared: Arc<Shared<E>>,
+}
+
+impl<'scope, E> Scope<'scope, '_, E>
+where
+    E: Send + 'scope,
+{
+    /// Spawns a task on a new scoped thread.
+    ///
+    /// The task is given the [`Cancellation`] of the scope, which it should check
+    /// periodically if it is long-running. A panic within the task is caught, and
+    /// reported as a failure.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: FnOnce(Cancellation) -> Fallible<E> + Send + 'scope,
+    {
+        let shared = Arc::clone(&self.shared);
+
+        let _ = self.inner.spawn(move || {
+            let cancellation = Cancellation {
+                flag: Arc::clone(&shared.cancelled),
+            };
+
+            if shared.failed.load(Ordering::Acquire) {
+                return;
+            }
+
+            if let Fail(failure) = Fallible::catch_unwind(AssertUnwindSafe(|| task(cancellation))) {
+                let mut failures = shared
+                    .failures
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                failures.push(failure);
+
+                // Cancelling while holding the lock ensures that this failure is recorded
+                // before any failure caused by the cancellation itself.
+                if shared.policy == Policy::FailFast {
+                    shared.failed.store(true, Ordering::Release);
+                    shared.cancelled.store(true, Ordering::Release);
+                }
+            }
+        });
+    }
+
+    /// Returns the [`Cancellation`] of the scope.
+    #[inline]
+    pub fn cancellation(&self) -> Cancellation {
+        Cancellation {
+            flag: Arc::clone(&self.shared.cancelled),
+        }
+    }
+}
+
+/// Runs every task spawned within `f` in parallel using [`std::thread::scope`], and waits
+/// for all of them to finish.
+///
+/// The outcome is `Success` if every task succeeded. Otherwise it contains the failures
+/// in the order they occurred, which is only the first failure under [`Policy::FailFast`].
+///
+/// ```rust
+/// # use fallible_option::{thread::{self, Policy}, Fallible::{self, Fail, Success}};
+/// let outcome = thread::scope(Policy::FailFast, |scope| {
+///     scope.spawn(|_| Fail("first check failed"));
+///     scope.spawn(|cancellation| {
+///         while !cancellation.is_cancelled() {
+///             std::thread::yield_now();
+///         }
+///         Fail("cancelled")
+///     });
+/// });
+///
+/// let failures = outcome.unwrap_fail();
+/// assert_eq!(failures.len(), 1);
+/// assert_eq!(failures[0].to_string(), "first check failed");
+/// ```
+pub fn scope<'env, E, F>(policy: Policy, f: F) -> Fallible<Vec<Panicked<E>>>
+where
+    E: Send,
+    F: for<'scope> FnOnce(&Scope<'scope, 'env, E>),
+{
+    let shared = Arc::new(Shared {
+        policy,
+        cancelled: Arc::new(AtomicBool::new(false)),
+        failed: AtomicBool::new(false),
+        failures: Mutex::new(Vec::new()),
+    });
+
+    std::thread::scope(|inner| {
+        f(&Scope {
+            inner,
+            shared: Arc::clone(&shared),
+        })
+    });
+
+    // Every scoped thread has been joined, and has released its reference to `shared`.
+    let mut failures = Arc::into_inner(shared)
+        .expect("scoped threads have been joined")
+        .failures
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if policy == Policy::FailFast {
+        failures.truncate(1);
+    }
+
+    if failures.is_empty() {
+        Success
+    } else {
+        Fail(failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::vec::Vec;
+
+    use super::{scope, Policy};
+    use crate::{
+        Fallible::{Fail, Success},
+        Panicked,
+    };
+
+    #[test]
+    fn all_tasks_succeed() {
+        let completed = AtomicUsize::new(0);
+
+        let outcome = scope::<&str, _>(Policy::FailFast, |scope| {
+            for _ in 0..8 {
+                scope.spawn(|_| {
+                    let _ = completed.fetch_add(1, Ordering::Relaxed);
+                    Success
+                });
+            }
+        });
+
+        assert!(outcome.is_successful());
+        assert_eq!(completed.load(Ordering::Relaxed), 8);
+    }
+
+    #[test]
+    fn collect_all_reports_every_failure() {
+        let outcome = scope(Policy::CollectAll, |scope| {
+            for n in 0..6u32 {
+                scope.spawn(move |cancellation| {
+                    assert!(!cancellation.is_cancelled());
+                    if n % 2 == 1 {
+                        Fail(n)
+                    } else {
+                        Success
+                    }
+                });
+            }
+        });
+
+        let mut failures: Vec<u32> = outcome
+            .unwrap_fail()
+            .into_iter()
+            .filter_map(Panicked::into_error)
+            .collect();
+        failures.sort_unstable();
+
+        assert_eq!(failures, [1, 3, 5]);
+    }
+
+    #[test]
+    fn fail_fast_cancels_remaining_tasks() {
+        let observed = AtomicUsize::new(0);
+
+        let outcome = scope(Policy::FailFast, |scope| {
+            for _ in 0..4 {
+                scope.spawn(|cancellation| {
+                    while !cancellation.is_cancelled() {
+                        std::thread::yield_now();
+                    }
+                    let _ = observed.fetch_add(1, Ordering::Relaxed);
+                    Fail("cancelled")
+                });
+            }
+
+            scope.spawn(|_| Fail("failed"));
+        });
+
+        let failures = outcome.unwrap_fail();
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(failures[0], Panicked::Failed("failed")));
+        assert_eq!(observed.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn panics_are_failures() {
+        let outcome = scope::<&str, _>(Policy::CollectAll, |scope| {
+            scope.spawn(|_| panic!("check crashed"));
+            scope.spawn(|_| Success);
+        });
+
+        let failures = outcome.unwrap_fail();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].message(), Some("check crashed"));
+    }
+
+    #[test]
+    fn manual_cancellation() {
+        let outcome = scope::<&str, _>(Policy::CollectAll, |scope| {
+            scope.cancellation().cancel();
+            scope.spawn(|cancellation| {
+                if cancellation.is_cancelled() {
+                    Success
+                } else {
+                    Fail("not cancelled")
+                }
+            });
+        });
+
+        assert!(outcome.is_successful());
+    }
+
+    #[test]
+    fn manual_cancellation_does_not_skip_tasks() {
+        let outcome = scope(Policy::FailFast, |scope| {
+            scope.cancellation().cancel();
+            scope.spawn(|cancellation| {
+                assert!(cancellation.is_cancelled());
+                Fail("stopped early")
+            });
+        });
+
+        let failures = outcome.unwrap_fail();
+        assert_eq!(failures.len(), 1);
+        assert!(matches!(failures[0], Panicked::Failed("stopped early")));
+    }
+}
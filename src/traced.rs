@@ -0,0 +1,159 @@
+use core::fmt::{self, Display};
+use std::backtrace::Backtrace;
+
+use crate::Fallible::{self, Fail, Success};
+
+/// An error `E` annotated with a [`Backtrace`] captured when the `Traced` error was constructed.
+///
+/// The backtrace is captured using [`Backtrace::capture`], and so is only actually
+/// resolved if enabled through the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment
+/// variables.
+///
+/// Since `Traced<E>` can be constructed `From<E>`, applying `?` to a plain `Fallible<E>`
+/// within a function returning `Fallible<Traced<E>>` captures a backtrace automatically,
+/// at the point where the failure is first propagated:
+///
+/// ```rust
+/// # use fallible_option::{fallible_try, Traced, Fallible::{self, Fail, Success}};
+/// fn write_to_disk() -> Fallible<&'static str> {
+///     Fail("disk is full")
+/// }
+///
+/// fn save() -> Fallible<Traced<&'static str>> {
+///     fallible_try!(write_to_disk());
+///     Success
+/// }
+///
+/// let err = save().unwrap_fail();
+///
+/// assert_eq!(err.error(), &"disk is full");
+/// ```
+#[derive(Debug)]
+pub struct Traced<E> {
+    error: E,
+    backtrace: Backtrace,
+}
+
+impl<E> Traced<E> {
+    /// Annotates `error` with a backtrace of the current call stack.
+    #[inline]
+    pub fn new(error: E) -> Self {
+        Traced {
+            error,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Borrows the annotated error.
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Borrows the backtrace captured when the failure was produced.
+    #[inline]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Discards the backtrace, returning the annotated error.
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E> From<E> for Traced<E> {
+    #[inline]
+    fn from(value: E) -> Self {
+        Traced::new(value)
+    }
+}
+
+impl<E> Display for Traced<E>
+where
+    E: Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E> std::error::Error for Traced<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> Fallible<E> {
+    /// Annotates the contained error (if any) with a backtrace of the current call stack.
+    ///
+    /// ```rust
+    /// # use fallible_option::Fallible::{self, Fail};
+    /// let err = Fail("uh oh!").traced().unwrap_fail();
+    ///
+    /// assert_eq!(err.into_error(), "uh oh!");
+    /// ```
+    #[inline]
+    pub fn traced(self) -> Fallible<Traced<E>> {
+        match self {
+            Success => Success,
+            Fail(e) => Fail(Traced::new(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::backtrace::Backtrace;
+
+    use crate::{
+        fallible_try,
+        Fallible::{self, Fail, Success},
+        Traced,
+    };
+
+    fn write_to_disk() -> Fallible<u8> {
+        Fail(1)
+    }
+
+    /// Asserts that a backtrace was captured for `err`, exactly when `Backtrace::capture`
+    /// would capture one in the current environment.
+    fn assert_captured(err: &Traced<u8>) {
+        assert_eq!(err.backtrace().status(), Backtrace::capture().status());
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn residual_conversion_captures() {
+        fn save() -> Fallible<Traced<u8>> {
+            write_to_disk()?;
+            Success
+        }
+
+        let err = save().unwrap_fail();
+        assert_captured(&err);
+        assert_eq!(err.into_error(), 1);
+    }
+
+    #[test]
+    fn fallible_try_conversion_captures() {
+        fn save() -> Fallible<Traced<u8>> {
+            fallible_try!(write_to_disk());
+            Success
+        }
+
+        let err = save().unwrap_fail();
+        assert_captured(&err);
+        assert_eq!(err.error(), &1);
+    }
+
+    #[test]
+    fn traced_success() {
+        assert!(Success::<u8>.traced().is_successful());
+    }
+}
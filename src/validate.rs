@@ -0,0 +1,175 @@
+use core::fmt::{self, Display};
+
+/// Error produced by the `validate` method generated by [`derive(Validate)`](crate::Validate),
+/// identifying the field which failed validation, and why.
+///
+/// ```rust
+/// # use fallible_option::{Validate, ValidationError, Fallible::{self, Fail, Success}};
+/// fn validate_name(name: &&'static str) -> Fallible<&'static str> {
+///     if name.is_empty() {
+///         Fail("name is empty")
+///     } else {
+///         Success
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct Person {
+///     #[validate(with = "validate_name")]
+///     name: &'static str,
+///     #[validate(range(0..=150))]
+///     age: u32,
+/// }
+///
+/// let person = Person { name: "Jane", age: 200 };
+///
+/// assert_eq!(
+///     person.validate(),
+///     Fail(ValidationError::new("age", "must be within 0..=150"))
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct ValidationError {
+    field: &'static str,
+    reason: &'static str,
+}
+
+impl ValidationError {
+    /// Constructs a new `ValidationError` for the named field.
+    #[inline]
+    pub const fn new(field: &'static str, reason: &'static str) -> Self {
+        ValidationError { field, reason }
+    }
+
+    /// Returns the name of the field which failed validation.
+    ///
+    /// Fields of tuple structs are named by their index.
+    #[inline]
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Returns the reason the field failed validation.
+    #[inline]
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Fallible::{self, Fail, Success},
+        Validate, ValidationError,
+    };
+
+    fn validate_number(x: &u32) -> Fallible<&'static str> {
+        match x {
+            0..=9 => Fail("number is too small"),
+            10..=30 => Success,
+            31.. => Fail("number is too large"),
+        }
+    }
+
+    #[derive(Validate)]
+    struct Config {
+        #[validate(range(1..=16))]
+        threads: u8,
+        #[validate(with = "validate_number")]
+        retries: u32,
+        #[validate(range(..100), with = "validate_number")]
+        timeout: u32,
+        _unvalidated: bool,
+    }
+
+    #[derive(Validate)]
+    struct Port(#[validate(range(1024..))] u16);
+
+    const MAX_OFFSET: i32 = 5;
+
+    #[derive(Validate)]
+    struct Offset(#[validate(range(-5..=MAX_OFFSET))] i32);
+
+    fn valid_config() -> Config {
+        Config {
+            threads: 4,
+            retries: 10,
+            timeout: 30,
+            _unvalidated: false,
+        }
+    }
+
+    #[test]
+    fn valid_struct() {
+        assert_eq!(valid_config().validate(), Success);
+    }
+
+    #[test]
+    fn range_failure() {
+        let config = Config {
+            threads: 0,
+            ..valid_config()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Fail(ValidationError::new("threads", "must be within 1..=16"))
+        );
+    }
+
+    #[test]
+    fn with_failure() {
+        let config = Config {
+            retries: 40,
+            ..valid_config()
+        };
+
+        assert_eq!(
+            config.validate(),
+            Fail(ValidationError::new("retries", "number is too large"))
+        );
+    }
+
+    #[test]
+    fn first_failure_is_returned() {
+        let config = Config {
+            threads: 20,
+            timeout: 200,
+            ..valid_config()
+        };
+        assert_eq!(config.validate().unwrap_fail().field(), "threads");
+
+        let config = Config {
+            timeout: 200,
+            ..valid_config()
+        };
+        assert_eq!(
+            config.validate(),
+            Fail(ValidationError::new("timeout", "must be within ..100"))
+        );
+    }
+
+    #[test]
+    fn tuple_struct() {
+        assert_eq!(Port(8080).validate(), Success);
+        assert_eq!(
+            Port(80).validate(),
+            Fail(ValidationError::new("0", "must be within 1024.."))
+        );
+
+        assert_eq!(Offset(-5).validate(), Success);
+        assert_eq!(
+            Offset(-6).validate(),
+            Fail(ValidationError::new("0", "must be within -5..=MAX_OFFSET"))
+        );
+    }
+}
@@ -0,0 +1,13 @@
+// Diagnostics differ between toolchains, so they are only checked on stable.
+#[cfg(not(feature = "nightly"))]
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+
+    #[cfg(feature = "derive")]
+    {
+        t.compile_fail("tests/ui/fallible/*.rs");
+        t.compile_fail("tests/ui/validate/*.rs");
+    }
+}
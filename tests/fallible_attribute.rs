@@ -0,0 +1,200 @@
+#![cfg(feature = "derive")]
+
+use fallible_option::{
+    fallible,
+    Fallible::{self, Fail, Success},
+};
+
+#[fallible]
+fn validate_number(x: u32) -> Result<(), &'static str> {
+    match x {
+        0..=9 => Err("number is too small"),
+        10..=30 => Ok(()),
+        31.. => Err("number is too large"),
+    }
+}
+
+#[test]
+fn tail_expressions() {
+    let outcome: Fallible<&str> = validate_number(5);
+
+    assert_eq!(outcome, Fail("number is too small"));
+    assert_eq!(validate_number(20), Success);
+    assert_eq!(validate_number(40), Fail("number is too large"));
+}
+
+#[fallible]
+fn early_returns(values: &[i32]) -> Result<(), String> {
+    for value in values {
+        if *value < 0 {
+            return Err(format!("{value} is negative"));
+        }
+
+        if *value == 0 {
+            return Ok(());
+        }
+    }
+
+    if values.is_empty() {
+        return Ok(());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn return_statements() {
+    assert_eq!(early_returns(&[1, -2]), Fail("-2 is negative".to_owned()));
+    assert_eq!(early_returns(&[0, -2]), Success);
+    assert_eq!(early_returns(&[]), Success);
+}
+
+fn parse(input: &str) -> Result<u32, std::num::ParseIntError> {
+    input.parse()
+}
+
+fn check(input: u32) -> Fallible<std::num::ParseIntError> {
+    let _ = input;
+    Success
+}
+
+#[fallible]
+fn other_expressions(input: &str) -> Result<(), std::num::ParseIntError> {
+    if input.is_empty() {
+        return check(0);
+    }
+
+    // Closures keep their own return type.
+    let parsed = (|| -> Result<u32, std::num::ParseIntError> {
+        let value = parse(input)?;
+        Ok(value)
+    })();
+
+    match parsed {
+        Ok(value) => check(value),
+        Err(_) => parse(input),
+    }
+}
+
+#[test]
+fn arbitrary_expressions() {
+    assert_eq!(other_expressions(""), Success);
+    assert_eq!(other_expressions("12"), Success);
+    assert!(other_expressions("twelve").is_fail());
+}
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    Parse(std::num::ParseIntError),
+    OutOfRange(u32),
+}
+
+impl From<std::num::ParseIntError> for ConfigError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        ConfigError::Parse(err)
+    }
+}
+
+fn check_range(threads: u32) -> Fallible<ConfigError> {
+    if threads > 64 {
+        Fail(ConfigError::OutOfRange(threads))
+    } else {
+        Success
+    }
+}
+
+#[fallible]
+fn question_marks(input: &str) -> Result<(), ConfigError> {
+    let threads = parse(input)?;
+    check_range(threads)?;
+
+    Ok(())
+}
+
+#[test]
+fn question_mark_operator() {
+    assert_eq!(question_marks("8"), Success);
+    assert_eq!(question_marks("80"), Fail(ConfigError::OutOfRange(80)));
+    assert!(matches!(
+        question_marks("eight"),
+        Fail(ConfigError::Parse(_))
+    ));
+}
+
+#[fallible]
+fn question_marks_in_macros(inputs: &[&str]) -> Result<(), ConfigError> {
+    let mut parsed = vec![parse(inputs[0])?, parse(inputs[1])?];
+    parsed.append(&mut vec![parse(inputs[0])?; 2]);
+
+    let message = format!("{} threads", parse(inputs[1])?);
+    assert!(parse(inputs[0])? > 0, "{message}");
+    assert_eq!(parsed.len(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn question_mark_in_macros() {
+    assert_eq!(question_marks_in_macros(&["1", "2"]), Success);
+    assert!(matches!(
+        question_marks_in_macros(&["1", "two"]),
+        Fail(ConfigError::Parse(_))
+    ));
+}
+
+#[fallible]
+fn write_config(out: &mut String, threads: u32) -> std::fmt::Result {
+    use std::fmt::Write;
+
+    if threads == 0 {
+        unreachable!("threads are validated")
+    }
+
+    write!(out, "threads = {threads}")
+}
+
+#[fallible]
+fn remove_lock(path: &std::path::Path) -> std::io::Result<()> {
+    std::fs::remove_file(path)
+}
+
+#[test]
+fn result_aliases() {
+    let mut out = String::new();
+    let outcome: Fallible<std::fmt::Error> = write_config(&mut out, 4);
+
+    assert_eq!(outcome, Success);
+    assert_eq!(out, "threads = 4");
+
+    let outcome: Fallible<std::io::Error> =
+        remove_lock(std::path::Path::new("fallible-option-does-not-exist"));
+    assert_eq!(
+        outcome.map(|err| err.kind()),
+        Fail(std::io::ErrorKind::NotFound)
+    );
+}
+
+struct Disk {
+    free: u64,
+}
+
+impl Disk {
+    #[fallible]
+    fn reserve(&mut self, bytes: u64) -> std::result::Result<(), &'static str> {
+        if bytes > self.free {
+            Err("disk is full")
+        } else {
+            self.free -= bytes;
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn methods() {
+    let mut disk = Disk { free: 10 };
+
+    assert_eq!(disk.reserve(4), Success);
+    assert_eq!(disk.reserve(8), Fail("disk is full"));
+    assert_eq!(disk.free, 6);
+}
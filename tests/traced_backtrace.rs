@@ -0,0 +1,44 @@
+#![cfg(feature = "backtrace")]
+
+// Whether backtraces are captured is read from the environment once per process, so
+// this lives in its own test binary, where the variable is set before any capture.
+
+use std::backtrace::BacktraceStatus;
+
+use fallible_option::{
+    fallible_try,
+    Fallible::{self, Fail, Success},
+    Traced,
+};
+
+fn write_to_disk() -> Fallible<&'static str> {
+    Fail("disk is full")
+}
+
+fn save() -> Fallible<Traced<&'static str>> {
+    fallible_try!(write_to_disk());
+    Success
+}
+
+#[test]
+fn captures_backtrace_when_enabled() {
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+
+    let err = save().unwrap_fail();
+    assert_eq!(err.backtrace().status(), BacktraceStatus::Captured);
+    assert_eq!(err.into_error(), "disk is full");
+
+    let err = write_to_disk().traced().unwrap_fail();
+    assert_eq!(err.backtrace().status(), BacktraceStatus::Captured);
+
+    #[cfg(feature = "nightly")]
+    {
+        fn save_with_residual() -> Fallible<Traced<&'static str>> {
+            write_to_disk()?;
+            Success
+        }
+
+        let err = save_with_residual().unwrap_fail();
+        assert_eq!(err.backtrace().status(), BacktraceStatus::Captured);
+    }
+}
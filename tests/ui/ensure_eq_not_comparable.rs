@@ -0,0 +1,12 @@
+use fallible_option::{ensure_eq, Fallible};
+
+struct Checksum(u32);
+
+fn verify(expected: Checksum, actual: Checksum) -> Fallible<&'static str> {
+    ensure_eq!(expected, actual, "checksum mismatch");
+    Fallible::Success
+}
+
+fn main() {
+    let _ = verify(Checksum(1), Checksum(2));
+}
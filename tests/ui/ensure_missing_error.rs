@@ -0,0 +1,10 @@
+use fallible_option::{ensure, Fallible};
+
+fn write(buffer: &[u8]) -> Fallible<&'static str> {
+    ensure!(!buffer.is_empty());
+    Fallible::Success
+}
+
+fn main() {
+    let _ = write(&[]);
+}
@@ -0,0 +1,9 @@
+use fallible_option::fail;
+
+fn write() {
+    fail!("nothing to write");
+}
+
+fn main() {
+    write();
+}
@@ -0,0 +1,12 @@
+use fallible_option::{fail, Fallible};
+
+#[derive(Debug)]
+struct WriteError;
+
+fn write() -> Fallible<WriteError> {
+    fail!("nothing to write");
+}
+
+fn main() {
+    let _ = write();
+}
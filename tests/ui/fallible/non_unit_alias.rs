@@ -0,0 +1,10 @@
+use fallible_option::fallible;
+
+type Result = std::result::Result<u32, String>;
+
+#[fallible]
+fn threads() -> Result {
+    Ok(4)
+}
+
+fn main() {}
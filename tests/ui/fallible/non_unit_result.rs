@@ -0,0 +1,10 @@
+use fallible_option::fallible;
+
+#[fallible]
+fn parse(input: &str) -> Result<u32, std::num::ParseIntError> {
+    input.parse()
+}
+
+fn main() {
+    let _ = parse("10");
+}
@@ -0,0 +1,9 @@
+use fallible_option::fallible;
+
+#[fallible]
+fn is_small(input: &str) -> Result<(), std::num::ParseIntError> {
+    let _small = matches!(input.parse::<u32>()?, 0..=9);
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use fallible_option::fallible;
+
+#[fallible(strict)]
+fn write() -> Result<(), &'static str> {
+    Ok(())
+}
+
+fn main() {
+    let _ = write();
+}
@@ -0,0 +1,9 @@
+use fallible_option::Validate;
+
+#[derive(Validate)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+fn main() {}
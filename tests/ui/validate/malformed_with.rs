@@ -0,0 +1,13 @@
+use fallible_option::{Fallible, Validate};
+
+fn is_hostname(_: &str) -> Fallible<&'static str> {
+    Fallible::Success
+}
+
+#[derive(Validate)]
+struct Config {
+    #[validate(with = "is hostname")]
+    host: String,
+}
+
+fn main() {}
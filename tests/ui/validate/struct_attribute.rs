@@ -0,0 +1,7 @@
+use fallible_option::Validate;
+
+#[derive(Validate)]
+#[validate(range(1..=16))]
+struct Threads(u8);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use fallible_option::Validate;
+
+#[derive(Validate)]
+struct Config {
+    #[validate(length(1..64))]
+    name: String,
+}
+
+fn main() {}